@@ -0,0 +1,64 @@
+//! Generates the registry of solutions that are compiled into the main binary with the `registry`
+//! feature, so `all` and `time` can run them in-process. See `src/template/registry.rs`.
+use std::{
+    env,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+const BIN_DIR: &str = "src/bin";
+
+fn main() {
+    println!("cargo::rerun-if-changed={BIN_DIR}");
+
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+
+    let mut bins: Vec<(u16, u8, PathBuf)> = fs::read_dir(manifest_dir.join(BIN_DIR))
+        .map(|entries| {
+            entries
+                .filter_map(|entry| {
+                    let path = entry.ok()?.path();
+                    let (year, day) = parse_bin_name(&path)?;
+                    Some((year, day, path))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    bins.sort_unstable();
+
+    let mut modules = String::new();
+    let mut entries = String::new();
+
+    // the `#![feature(...)]`s of the bins are enabled for the whole main binary when it is built,
+    // see `registry::build_main_binary`.
+    for (year, day, path) in bins {
+        let module = format!("day_{year}_{day:02}");
+
+        let _ = writeln!(
+            modules,
+            "#[allow(warnings)]\n#[path = {:?}]\nmod {module};\n",
+            path.display().to_string()
+        );
+        let _ = writeln!(
+            entries,
+            "    RegisteredDay {{ year: advent_of_code::year!({year}), day: advent_of_code::day!({day}), run: {module}::main }},"
+        );
+    }
+
+    let registry = format!("{modules}pub static DAYS: &[RegisteredDay] = &[\n{entries}];\n");
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("registry.rs");
+    fs::write(out_path, registry).unwrap();
+}
+
+/// Year and day of a solution bin named like `2025_01.rs`.
+fn parse_bin_name(path: &Path) -> Option<(u16, u8)> {
+    if path.extension()? != "rs" {
+        return None;
+    }
+
+    let (year, day) = path.file_stem()?.to_str()?.split_once('_')?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
@@ -1,7 +1,7 @@
 use itertools::Itertools;
 use std::str::FromStr;
 
-advent_of_code::solution!(1);
+advent_of_code::solution!(2025, 1);
 
 pub fn part_one(input: &str) -> Option<u64> {
     let moves = parse(input);
@@ -72,7 +72,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(3));
     }
 
@@ -100,7 +100,7 @@ mod tests {
         // The dial is rotated L82 to point at 32; during this rotation, it points at 0 once.
         assert_eq!(count_zero_pass(-82, 14), 1);
 
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(6));
     }
 
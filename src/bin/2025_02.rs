@@ -2,7 +2,7 @@ use advent_of_code::{Range, debug_println};
 use itertools::Itertools;
 use std::str::FromStr;
 
-advent_of_code::solution!(2);
+advent_of_code::solution!(2025, 2);
 
 pub fn part_one(input: &str) -> Option<i128> {
     let ranges = parse(input);
@@ -90,7 +90,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(1227775554));
     }
 
@@ -118,7 +118,7 @@ mod tests {
             vec![111]
         );
 
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(4174379265));
     }
 
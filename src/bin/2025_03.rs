@@ -1,6 +1,6 @@
 use itertools::Itertools;
 
-advent_of_code::solution!(3);
+advent_of_code::solution!(2025, 3);
 
 pub fn part_one(input: &str) -> Option<u32> {
     let batteries = parse(input);
@@ -54,14 +54,14 @@ mod tests {
     fn test_part_one() {
         assert_eq!(part_one("987654321111111"), Some(98));
         assert_eq!(part_one("811111111111119"), Some(89));
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(357));
     }
 
     #[test]
     fn test_part_two() {
         assert_eq!(part_two("987654321111111"), Some(987654321111));
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(3121910778619));
     }
 }
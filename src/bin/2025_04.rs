@@ -7,7 +7,7 @@ use std::ascii;
 use std::collections::{HashMap, HashSet};
 use std::iter::repeat;
 
-advent_of_code::solution!(4);
+advent_of_code::solution!(2025, 4);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let map = Map2D::new(input);
@@ -112,13 +112,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(13));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(43));
     }
 }
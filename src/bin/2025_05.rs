@@ -2,7 +2,7 @@ use advent_of_code::Range;
 use itertools::Itertools;
 use std::str::FromStr;
 
-advent_of_code::solution!(5);
+advent_of_code::solution!(2025, 5);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let (ranges, numbers) = parse(input);
@@ -69,13 +69,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(3));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(14));
     }
 }
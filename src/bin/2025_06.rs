@@ -7,7 +7,7 @@ use nom::multi::{many_till, many1};
 use nom::sequence::{delimited, terminated};
 use nom::{IResult, Parser};
 
-advent_of_code::solution!(6);
+advent_of_code::solution!(2025, 6);
 
 pub fn part_one(input: &str) -> Option<u128> {
     let (numbers, ops) = parse1(input);
@@ -88,13 +88,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(4277556));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(3263827));
     }
 }
@@ -9,7 +9,7 @@ use std::string::String;
 
 use advent_of_code::{Map2D, debug_println};
 
-advent_of_code::solution!(7);
+advent_of_code::solution!(2025, 7);
 
 pub fn part_one(input: &str) -> Option<usize> {
     Some(trace_beams(&Map2D::new(input)).0)
@@ -91,13 +91,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(21));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(40));
     }
 }
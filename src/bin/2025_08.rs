@@ -6,7 +6,7 @@ use nom::multi::many1;
 use nom::sequence::{preceded, terminated};
 use nom::{IResult, Parser};
 
-advent_of_code::solution!(8);
+advent_of_code::solution!(2025, 8);
 
 pub fn part_one(input: &str) -> Option<usize> {
     part_one_iter(1000, input)
@@ -135,14 +135,17 @@ mod tests {
     #[test]
     fn test_part_one() {
         debug_println!("TEST PART ONE");
-        let result = part_one_iter(10, &advent_of_code::template::read_file("examples", DAY));
+        let result = part_one_iter(
+            10,
+            &advent_of_code::template::read_file("examples", YEAR, DAY),
+        );
         assert_eq!(result, Some(40));
     }
 
     #[test]
     fn test_part_two() {
         debug_println!("TEST PART TWO");
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(216 * 117));
     }
 }
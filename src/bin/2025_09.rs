@@ -9,7 +9,7 @@ use nom::{IResult, Parser};
 use std::cmp::max;
 use std::iter::once;
 
-advent_of_code::solution!(9);
+advent_of_code::solution!(2025, 9);
 
 pub fn part_one(input: &str) -> Option<u64> {
     let points = parse(input);
@@ -121,14 +121,14 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(50));
     }
 
     // PART TWO HAS SPECIAL INPUT SHAPE
     // #[test]
     // fn test_part_two() {
-    //     let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+    //     let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
     //     assert_eq!(result, None);
     // }
 }
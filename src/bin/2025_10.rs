@@ -10,7 +10,7 @@ use std::collections::HashSet;
 use z3::ast::Int;
 use z3::{Optimize, SatResult};
 
-advent_of_code::solution!(10);
+advent_of_code::solution!(2025, 10);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let machines = parse(input);
@@ -191,13 +191,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(7));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(33));
     }
 }
@@ -6,7 +6,7 @@ use petgraph::visit::{IntoNeighbors, Reversed, Walker};
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::hash::RandomState;
 
-advent_of_code::solution!(11);
+advent_of_code::solution!(2025, 11);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let cables = parse(input);
@@ -144,14 +144,14 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(5));
     }
 
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", YEAR, DAY, 2,
         ));
         assert_eq!(result, Some(2));
     }
@@ -3,7 +3,7 @@
 use advent_of_code::Map2D;
 use std::str::FromStr;
 
-advent_of_code::solution!(12);
+advent_of_code::solution!(2025, 12);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let (_presents, regions) = parse(input);
@@ -65,13 +65,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, None);
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, None);
     }
 }
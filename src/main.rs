@@ -1,4 +1,9 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::examples::ExamplesOptions;
+use advent_of_code::template::commands::solve::SolveOptions;
+use advent_of_code::template::commands::time::TimeOptions;
+use advent_of_code::template::commands::{
+    all, download, examples, read, readme, scaffold, solve, time, verify,
+};
 use args::{AppArguments, parse};
 
 #[cfg(feature = "today")]
@@ -7,75 +12,307 @@ use advent_of_code::template::Day;
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
+    use advent_of_code::template::{
+        BenchPolicy, Day, Format, Limits, Percentage, Statistic, Year, parse_bytes, parse_duration,
+    };
     use std::process;
 
     pub enum AppArguments {
         Download {
+            year: Year,
             day: Day,
         },
         Read {
+            year: Year,
             day: Day,
         },
+        Examples {
+            year: Year,
+            day: Day,
+            list: bool,
+            blocks: Vec<usize>,
+            overwrite: bool,
+        },
         Scaffold {
+            year: Year,
             day: Day,
             download: bool,
             overwrite: bool,
+            template: Option<String>,
         },
         Solve {
+            year: Year,
             day: Day,
             release: bool,
+            time: bool,
             dhat: bool,
             submit: Option<u8>,
+            input: Option<String>,
+            example: Option<Option<u8>>,
+            format: Option<Format>,
+            bench: BenchPolicy,
+            watch: bool,
         },
         All {
+            year: Year,
             release: bool,
+            example: bool,
+            jobs: Option<usize>,
+            format: Option<Format>,
+            time: bool,
+            bench: BenchPolicy,
+            limits: Limits,
         },
         Time {
+            year: Year,
             all: bool,
             day: Option<Day>,
             store: bool,
+            jobs: Option<usize>,
+            format: Option<Format>,
+            bench: BenchPolicy,
+            stat: Statistic,
+            history: bool,
+            compare: Option<String>,
+            fail_above: Option<Percentage>,
+            memory: bool,
+            chart: bool,
+            limits: Limits,
+        },
+        Verify {
+            year: Year,
+            days: Vec<Day>,
+            release: bool,
+        },
+        Readme {
+            year: Year,
+            stars: bool,
         },
         #[cfg(feature = "today")]
         Today,
     }
 
+    /// Reads the `--year` option, falling back to the `AOC_YEAR` environment variable.
+    fn parse_year(args: &mut pico_args::Arguments) -> Result<Year, Box<dyn std::error::Error>> {
+        match args.opt_value_from_str("--year")? {
+            Some(year) => Ok(year),
+            None => Year::from_env()
+                .ok_or_else(|| "no year specified. Pass `--year <year>` or set AOC_YEAR.".into()),
+        }
+    }
+
+    /// Checks that the day is part of the year's event, e.g. rejects day 13 in 2025.
+    fn check_day(year: Year, day: Day) -> Result<Day, Box<dyn std::error::Error>> {
+        if year.has_day(day) {
+            Ok(day)
+        } else {
+            Err(format!(
+                "expecting a day number between 1 and {} for {year}",
+                year.day_count()
+            )
+            .into())
+        }
+    }
+
+    /// Reads the `--jobs` option, which has to be at least 1.
+    fn parse_jobs(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Option<usize>, Box<dyn std::error::Error>> {
+        match args.opt_value_from_str("--jobs")? {
+            Some(0) => Err("expecting `--jobs` to be at least 1".into()),
+            jobs => Ok(jobs),
+        }
+    }
+
+    /// Reads the `--bench-time`, `--min-samples`, `--max-samples` and `--warmup` options.
+    fn parse_bench_policy(
+        args: &mut pico_args::Arguments,
+    ) -> Result<BenchPolicy, Box<dyn std::error::Error>> {
+        let default = BenchPolicy::default();
+
+        let policy = BenchPolicy {
+            bench_time: args
+                .opt_value_from_fn("--bench-time", parse_duration)?
+                .unwrap_or(default.bench_time),
+            min_samples: args
+                .opt_value_from_str("--min-samples")?
+                .unwrap_or(default.min_samples),
+            max_samples: args
+                .opt_value_from_str("--max-samples")?
+                .unwrap_or(default.max_samples),
+            warmup: args
+                .opt_value_from_str("--warmup")?
+                .unwrap_or(default.warmup),
+        };
+
+        policy.validate()?;
+
+        Ok(policy)
+    }
+
+    /// Reads the `--timeout` and `--max-memory` options.
+    fn parse_limits(args: &mut pico_args::Arguments) -> Result<Limits, Box<dyn std::error::Error>> {
+        Ok(Limits {
+            timeout: args.opt_value_from_fn("--timeout", parse_duration)?,
+            max_memory: args.opt_value_from_fn("--max-memory", parse_bytes)?,
+        })
+    }
+
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
-        let app_args = match args.subcommand()?.as_deref() {
+        let subcommand = args.subcommand()?;
+
+        #[cfg(feature = "today")]
+        if subcommand.as_deref() == Some("today") {
+            return Ok(AppArguments::Today);
+        }
+
+        let year = parse_year(&mut args)?;
+
+        let app_args = match subcommand.as_deref() {
             Some("all") => AppArguments::All {
+                year,
                 release: args.contains("--release"),
+                example: args.contains("--example"),
+                jobs: parse_jobs(&mut args)?,
+                format: args.opt_value_from_str("--format")?,
+                time: args.contains("--time"),
+                bench: parse_bench_policy(&mut args)?,
+                limits: parse_limits(&mut args)?,
             },
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let history = args.contains("--history");
+                let compare = args.opt_value_from_str("--compare")?;
+                let fail_above = args.opt_value_from_str("--fail-above")?;
+                let memory = args.contains("--memory");
+                let chart = args.contains("--chart");
+                let jobs = parse_jobs(&mut args)?;
+                let format = args.opt_value_from_str("--format")?;
+                let bench = parse_bench_policy(&mut args)?;
+                let stat = args.opt_value_from_str("--stat")?.unwrap_or_default();
+                let limits = parse_limits(&mut args)?;
+                let day = args.opt_free_from_str()?;
+
+                if chart && !store {
+                    return Err("`--chart` requires `--store`".into());
+                }
 
                 AppArguments::Time {
+                    year,
                     all,
-                    day: args.opt_free_from_str()?,
+                    day: day.map(|day| check_day(year, day)).transpose()?,
                     store,
+                    jobs,
+                    format,
+                    bench,
+                    stat,
+                    history,
+                    compare,
+                    fail_above,
+                    memory,
+                    chart,
+                    limits,
                 }
             }
+            Some("verify") => {
+                let release = args.contains("--release");
+                let mut days = vec![];
+                while let Some(day) = args.opt_free_from_str()? {
+                    days.push(check_day(year, day)?);
+                }
+
+                AppArguments::Verify {
+                    year,
+                    days,
+                    release,
+                }
+            }
+            Some("readme") => AppArguments::Readme {
+                year,
+                stars: args.contains("--stars"),
+            },
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                year,
+                day: check_day(year, args.free_from_str()?)?,
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                year,
+                day: check_day(year, args.free_from_str()?)?,
             },
-            Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
-                download: args.contains("--download"),
-                overwrite: args.contains("--overwrite"),
-            },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
-            },
-            #[cfg(feature = "today")]
-            Some("today") => AppArguments::Today,
+            Some("examples") => {
+                let list = args.contains("--list");
+                let blocks = args.values_from_str("--block")?;
+                let overwrite = args.contains("--overwrite");
+
+                AppArguments::Examples {
+                    year,
+                    day: check_day(year, args.free_from_str()?)?,
+                    list,
+                    blocks,
+                    overwrite,
+                }
+            }
+            Some("scaffold") => {
+                let download = args.contains("--download");
+                let overwrite = args.contains("--overwrite");
+                let template = args.opt_value_from_str("--template")?;
+
+                AppArguments::Scaffold {
+                    year,
+                    day: check_day(year, args.free_from_str()?)?,
+                    download,
+                    overwrite,
+                    template,
+                }
+            }
+            Some("solve") => {
+                let release = args.contains("--release");
+                let time = args.contains("--time");
+                let submit = args.opt_value_from_str("--submit")?;
+                let dhat = args.contains("--dhat");
+                let input = args.opt_value_from_str("--input")?;
+                let is_example = args.contains("--example");
+                let format = args.opt_value_from_str("--format")?;
+                let bench = parse_bench_policy(&mut args)?;
+                let watch = args.contains("--watch");
+                let day = check_day(year, args.free_from_str()?)?;
+                // the example number is optional, so it is parsed as a free argument after the day.
+                let example = if is_example {
+                    Some(args.opt_free_from_str()?)
+                } else {
+                    None
+                };
+
+                if input.is_some() && is_example {
+                    return Err("`--input` cannot be combined with `--example`".into());
+                }
+
+                if watch && (submit.is_some() || format.is_some()) {
+                    return Err("`--watch` cannot be combined with `--submit` or `--format`".into());
+                }
+
+                // stdin is used up by the first run, every rerun would get an empty input.
+                if watch && input.as_deref() == Some("-") {
+                    return Err("`--watch` cannot read the input from stdin, pass a file with `--input <path>`".into());
+                }
+
+                AppArguments::Solve {
+                    year,
+                    day,
+                    release,
+                    time,
+                    dhat,
+                    submit,
+                    input,
+                    example,
+                    format,
+                    bench,
+                    watch,
+                }
+            }
             Some(x) => {
                 eprintln!("Unknown command: {x}");
                 process::exit(1);
@@ -95,45 +332,160 @@ mod args {
     }
 }
 
+/// Every solution in `src/bin`, compiled into this binary by the build script.
+#[cfg(feature = "registry")]
+mod solutions {
+    use advent_of_code::template::registry::RegisteredDay;
+
+    include!(concat!(env!("OUT_DIR"), "/registry.rs"));
+}
+
 fn main() {
+    #[cfg(feature = "registry")]
+    advent_of_code::template::registry::register(solutions::DAYS);
+
     match parse() {
         Err(err) => {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
+            AppArguments::All {
+                year,
+                release,
+                example,
+                jobs,
+                format,
+                time,
+                bench,
+                limits,
+            } => all::handle(
+                year,
+                release,
+                example,
+                jobs.unwrap_or(1),
+                format,
+                time.then_some(bench),
+                limits,
+            ),
+            AppArguments::Time {
+                year,
+                day,
+                all,
+                store,
+                jobs,
+                format,
+                bench,
+                stat,
+                history,
+                compare,
+                fail_above,
+                memory,
+                chart,
+                limits,
+            } => {
+                if history {
+                    time::history(year, day);
+                } else {
+                    time::handle(
+                        year,
+                        day,
+                        &TimeOptions {
+                            run_all: all,
+                            store,
+                            jobs: jobs.unwrap_or(1),
+                            bench,
+                            format,
+                            statistic: stat,
+                            compare,
+                            fail_above,
+                            memory,
+                            chart,
+                            limits,
+                        },
+                    );
+                }
+            }
+            AppArguments::Verify {
+                year,
+                days,
+                release,
+            } => verify::handle(year, &days, release),
+            AppArguments::Readme { year, stars } => readme::handle(year, stars),
+            AppArguments::Download { year, day } => download::handle(year, day),
+            AppArguments::Read { year, day } => read::handle(year, day),
+            AppArguments::Examples {
+                year,
+                day,
+                list,
+                blocks,
+                overwrite,
+            } => examples::handle(
+                year,
+                day,
+                &ExamplesOptions {
+                    list,
+                    blocks,
+                    overwrite,
+                },
+            ),
             AppArguments::Scaffold {
+                year,
                 day,
                 download,
                 overwrite,
+                template,
             } => {
-                scaffold::handle(day, overwrite);
+                // download first, so that the template can use the puzzle title.
                 if download {
-                    download::handle(day);
+                    download::handle(year, day);
                 }
+                scaffold::handle(
+                    year,
+                    day,
+                    overwrite,
+                    template.as_deref().unwrap_or(scaffold::DEFAULT_TEMPLATE),
+                );
             }
             AppArguments::Solve {
+                year,
                 day,
                 release,
+                time,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                input,
+                example,
+                format,
+                bench,
+                watch,
+            } => solve::handle(
+                year,
+                day,
+                &SolveOptions {
+                    release,
+                    time,
+                    dhat,
+                    submit_part: submit,
+                    input,
+                    example,
+                    format,
+                    bench,
+                    watch,
+                },
+            ),
             #[cfg(feature = "today")]
             AppArguments::Today => {
                 match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
+                    Some((year, day)) => {
+                        download::handle(year, day);
+                        scaffold::handle(year, day, false, scaffold::DEFAULT_TEMPLATE);
+                        read::handle(year, day)
                     }
                     None => {
                         eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 12th of december. Please use `scaffold` with a specific day."
+                            "`today` command can only be run during the advent days of \
+                            december. Please use `scaffold` with a specific day."
                         );
                         process::exit(1)
                     }
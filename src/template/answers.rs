@@ -0,0 +1,255 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::{Error, ErrorKind},
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, Year};
+
+static ANSWERS_FILE_PATH: &str = "./data/answers.json";
+
+/// Represents the known correct answers of a single day.
+#[derive(Clone, Debug)]
+pub struct Answer {
+    pub year: Year,
+    pub day: Day,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+/// Represents the known correct answers for a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    pub data: Vec<Answer>,
+}
+
+impl Answers {
+    /// Dehydrate answers to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(ANSWERS_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers from a JSON file. If not present, returns no answers.
+    /// A file that cannot be read is an error, so that a bad entry does not hide all answers.
+    pub fn read_from_file() -> Result<Self, String> {
+        match fs::read_to_string(ANSWERS_FILE_PATH) {
+            Ok(content) => Answers::try_from(content)
+                .map_err(|e| format!("could not parse `{ANSWERS_FILE_PATH}`: {e}")),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(format!("could not read `{ANSWERS_FILE_PATH}`: {e}")),
+        }
+    }
+
+    /// The known answer for one part of a day.
+    pub fn get(&self, year: Year, day: Day, part: u8) -> Option<&str> {
+        let answer = self.data.iter().find(|a| a.year == year && a.day == day)?;
+        match part {
+            1 => answer.part_1.as_deref(),
+            2 => answer.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Sets the answer for one part of a day, keeping the data sorted.
+    pub fn set(&mut self, year: Year, day: Day, part: u8, value: &str) {
+        let index = match self
+            .data
+            .iter()
+            .position(|a| a.year == year && a.day == day)
+        {
+            Some(index) => index,
+            None => {
+                self.data.push(Answer {
+                    year,
+                    day,
+                    part_1: None,
+                    part_2: None,
+                });
+                self.data.sort_unstable_by_key(|a| (a.year, a.day));
+                self.data
+                    .iter()
+                    .position(|a| a.year == year && a.day == day)
+                    .unwrap()
+            }
+        };
+
+        let answer = &mut self.data[index];
+        match part {
+            1 => answer.part_1 = Some(value.to_string()),
+            2 => answer.part_2 = Some(value.to_string()),
+            _ => {}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Answers> for JsonValue {
+    fn from(value: Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Answers {
+            data: json_data
+                .iter()
+                .map(Answer::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answer> for JsonValue {
+    fn from(value: &Answer) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("year".into(), JsonValue::String(value.year.to_string()));
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+
+        for (key, part) in [("part_1", &value.part_1), ("part_2", &value.part_2)] {
+            map.insert(
+                key.into(),
+                match part {
+                    Some(x) => JsonValue::String(x.clone()),
+                    None => JsonValue::Null,
+                },
+            );
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+/// Largest integer a JSON number holds exactly, larger answers have to be quoted.
+const MAX_EXACT_NUMBER: f64 = 9_007_199_254_740_992_f64;
+
+impl TryFrom<&JsonValue> for Answer {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answer to be a JSON object.")?;
+
+        let year = json
+            .get("year")
+            .and_then(|v| v.get::<String>())
+            .and_then(|year| Year::from_str(year).ok())
+            .ok_or("Expected answer.year to be a Year struct.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected answer.day to be a Day struct.")?;
+
+        // parts may be omitted when answers are entered by hand, and numbers written unquoted.
+        let part = |key: &str| match json.get(key) {
+            None | Some(JsonValue::Null) => Ok(None),
+            Some(JsonValue::String(answer)) => Ok(Some(answer.clone())),
+            Some(JsonValue::Number(n)) if n.fract() == 0_f64 && n.abs() <= MAX_EXACT_NUMBER => {
+                Ok(Some(format!("{n:.0}")))
+            }
+            Some(_) => Err(format!(
+                "Expected answer.{key} of {year} day {day} to be a string or an integer below 2^53."
+            )),
+        };
+
+        Ok(Answer {
+            year,
+            day,
+            part_1: part("part_1")?,
+            part_2: part("part_2")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Answers;
+    use crate::{day, year};
+
+    #[test]
+    fn handles_json_answers() {
+        let json = r#"{ "data": [{ "year": "2025", "day": "01", "part_1": "1145", "part_2": null }, { "year": "2025", "day": "02", "part_2": "7" }] }"#.to_string();
+        let answers = Answers::try_from(json).unwrap();
+        assert_eq!(answers.get(year!(2025), day!(1), 1), Some("1145"));
+        assert_eq!(answers.get(year!(2025), day!(1), 2), None);
+        assert_eq!(answers.get(year!(2025), day!(2), 1), None);
+        assert_eq!(answers.get(year!(2025), day!(2), 2), Some("7"));
+        assert_eq!(answers.get(year!(2024), day!(1), 1), None);
+    }
+
+    #[test]
+    fn handles_number_answers() {
+        let json = r#"{ "data": [{ "year": "2025", "day": "01", "part_1": 1234, "part_2": -7 }] }"#
+            .to_string();
+        let answers = Answers::try_from(json).unwrap();
+        assert_eq!(answers.get(year!(2025), day!(1), 1), Some("1234"));
+        assert_eq!(answers.get(year!(2025), day!(1), 2), Some("-7"));
+
+        let json = r#"{ "data": [{ "year": "2025", "day": "03", "part_1": 1.5 }] }"#.to_string();
+        let error = Answers::try_from(json).unwrap_err();
+        assert!(error.contains("answer.part_1 of 2025 day 03"), "{error}");
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_answers() {
+        let json = r#"{ "data": [{ "day": "01", "part_1": "1" }] }"#.to_string();
+        Answers::try_from(json).unwrap();
+    }
+
+    #[test]
+    fn sets_answers_in_order() {
+        let mut answers = Answers::default();
+        answers.set(year!(2025), day!(3), 1, "3");
+        answers.set(year!(2025), day!(1), 2, "1");
+        answers.set(year!(2025), day!(3), 2, "33");
+
+        assert_eq!(answers.data.len(), 2);
+        assert_eq!(answers.data[0].day, day!(1));
+        assert_eq!(answers.get(year!(2025), day!(3), 1), Some("3"));
+        assert_eq!(answers.get(year!(2025), day!(3), 2), Some("33"));
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let mut answers = Answers::default();
+        answers.set(year!(2025), day!(1), 1, "42");
+        let json = tinyjson::JsonValue::from(answers).stringify().unwrap();
+        let parsed = Answers::try_from(json).unwrap();
+        assert_eq!(parsed.get(year!(2025), day!(1), 1), Some("42"));
+        assert_eq!(parsed.get(year!(2025), day!(1), 2), None);
+    }
+}
@@ -0,0 +1,487 @@
+/// Minimal Advent of Code HTTP client: downloads inputs and puzzles, submits answers.
+use std::{env, fmt::Display, fs, io, path::PathBuf, time::Duration};
+
+use ureq::Agent;
+
+use crate::template::{Day, Year, get_data_dir};
+
+/// Default location of the Advent of Code website. Override with `AOC_BASE_URL`.
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Advent of Code asks automated tools to identify themselves.
+const USER_AGENT: &str = concat!(
+    "github.com/xsebek/aoc-rust-2025 advent_of_code/",
+    env!("CARGO_PKG_VERSION")
+);
+
+#[derive(Debug)]
+pub enum AocCommandError {
+    SessionNotFound,
+    HttpStatus(u16),
+    RateLimited(String),
+    Transport(ureq::Error),
+    IO(io::Error),
+}
+
+impl Display for AocCommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocCommandError::SessionNotFound => write!(
+                f,
+                "no session cookie found. Set ADVENT_OF_CODE_SESSION or create ~/.adventofcode.session."
+            ),
+            AocCommandError::HttpStatus(status) => {
+                write!(f, "server responded with HTTP status {status}.")
+            }
+            AocCommandError::RateLimited(message) => write!(f, "rate limited: {message}"),
+            AocCommandError::Transport(e) => write!(f, "request failed: {e}"),
+            AocCommandError::IO(e) => write!(f, "could not write file: {e}"),
+        }
+    }
+}
+
+impl From<ureq::Error> for AocCommandError {
+    fn from(e: ureq::Error) -> Self {
+        AocCommandError::Transport(e)
+    }
+}
+
+impl From<io::Error> for AocCommandError {
+    fn from(e: io::Error) -> Self {
+        AocCommandError::IO(e)
+    }
+}
+
+/// An authenticated connection to the Advent of Code website.
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    agent: Agent,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .user_agent(USER_AGENT)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent,
+        }
+    }
+
+    /// Creates a client using `AOC_BASE_URL` and the session cookie of the current user.
+    pub fn from_env() -> Result<Self, AocCommandError> {
+        Ok(Self::new(&base_url(), &read_session()?))
+    }
+
+    /// Fetches the puzzle input of a day.
+    pub fn input(&self, year: Year, day: Day) -> Result<String, AocCommandError> {
+        self.get(&format!("/{year}/day/{}/input", day.into_inner()))
+    }
+
+    /// Fetches the puzzle page of a day and converts its description to markdown.
+    pub fn puzzle(&self, year: Year, day: Day) -> Result<String, AocCommandError> {
+        let html = self.get(&format!("/{year}/day/{}", day.into_inner()))?;
+        Ok(puzzle_to_markdown(&html))
+    }
+
+    /// Submits an answer and returns the server's reply as plain text.
+    pub fn submit(
+        &self,
+        year: Year,
+        day: Day,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, AocCommandError> {
+        let url = format!("{}/{year}/day/{}/answer", self.base_url, day.into_inner());
+        let mut response = self
+            .agent
+            .post(&url)
+            .header("Cookie", &format!("session={}", self.session))
+            .send_form([("level", part.to_string().as_str()), ("answer", answer)])?;
+
+        check_status(response.status().as_u16())?;
+        let body = response.body_mut().read_to_string()?;
+
+        let message = html_to_markdown(first_element(&body, "<article", "</article>"))
+            .trim()
+            .to_string();
+
+        if message.contains("You gave an answer too recently") {
+            Err(AocCommandError::RateLimited(message))
+        } else {
+            Ok(message)
+        }
+    }
+
+    fn get(&self, path: &str) -> Result<String, AocCommandError> {
+        let mut response = self
+            .agent
+            .get(&format!("{}{path}", self.base_url))
+            .header("Cookie", &format!("session={}", self.session))
+            .call()?;
+
+        check_status(response.status().as_u16())?;
+        Ok(response.body_mut().read_to_string()?)
+    }
+}
+
+fn check_status(status: u16) -> Result<(), AocCommandError> {
+    match status {
+        200..=299 => Ok(()),
+        429 => Err(AocCommandError::RateLimited(
+            "too many requests, try again later.".into(),
+        )),
+        status => Err(AocCommandError::HttpStatus(status)),
+    }
+}
+
+/// Reads the session cookie from `ADVENT_OF_CODE_SESSION` or `~/.adventofcode.session`.
+/// This matches where aoc-cli looks for it.
+fn read_session() -> Result<String, AocCommandError> {
+    if let Ok(session) = env::var("ADVENT_OF_CODE_SESSION") {
+        return Ok(session);
+    }
+
+    let home = env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .ok_or(AocCommandError::SessionNotFound)?;
+
+    fs::read_to_string(PathBuf::from(home).join(".adventofcode.session"))
+        .ok()
+        .filter(|s| !s.trim().is_empty())
+        .ok_or(AocCommandError::SessionNotFound)
+}
+
+pub fn check() -> Result<(), AocCommandError> {
+    read_session().map(|_| ())
+}
+
+pub fn read(year: Year, day: Day) -> Result<String, AocCommandError> {
+    let puzzle = AocClient::from_env()?.puzzle(year, day)?;
+    let puzzle_path = get_puzzle_path(year, day);
+    fs::create_dir_all(get_data_dir("puzzles", year))?;
+    fs::write(&puzzle_path, &puzzle)?;
+    println!("{puzzle}");
+    Ok(puzzle)
+}
+
+pub fn download(year: Year, day: Day) -> Result<(), AocCommandError> {
+    let client = AocClient::from_env()?;
+    let input_path = get_input_path(year, day);
+    let puzzle_path = get_puzzle_path(year, day);
+
+    let input = client.input(year, day)?;
+    let puzzle = client.puzzle(year, day)?;
+
+    fs::create_dir_all(get_data_dir("inputs", year))?;
+    fs::create_dir_all(get_data_dir("puzzles", year))?;
+    fs::write(&input_path, input)?;
+    fs::write(&puzzle_path, puzzle)?;
+
+    println!("---");
+    println!("🎄 Successfully wrote input to \"{input_path}\".");
+    println!("🎄 Successfully wrote puzzle to \"{puzzle_path}\".");
+    Ok(())
+}
+
+pub fn submit(year: Year, day: Day, part: u8, result: &str) -> Result<String, AocCommandError> {
+    let message = AocClient::from_env()?.submit(year, day, part, result)?;
+    println!("{message}");
+    Ok(message)
+}
+
+/// Location of the Advent of Code website, `AOC_BASE_URL` if set.
+fn base_url() -> String {
+    env::var("AOC_BASE_URL").map_or_else(
+        |_| DEFAULT_BASE_URL.into(),
+        |url| url.trim_end_matches('/').into(),
+    )
+}
+
+/// URL of the puzzle page of a day, on the same site that inputs are downloaded from.
+pub fn puzzle_url(year: Year, day: Day) -> String {
+    format!("{}/{year}/day/{}", base_url(), day.into_inner())
+}
+
+/// Heading of a downloaded puzzle without the dashes, e.g. `Day 1: Secret Entrance`.
+pub fn puzzle_title(year: Year, day: Day) -> Option<String> {
+    fs::read_to_string(get_puzzle_path(year, day))
+        .ok()
+        .as_deref()
+        .and_then(parse_title)
+}
+
+fn parse_title(markdown: &str) -> Option<String> {
+    let heading = markdown.lines().find_map(|l| l.strip_prefix("## "))?;
+    Some(heading.trim().trim_matches('-').trim().to_string()).filter(|t| !t.is_empty())
+}
+
+fn get_input_path(year: Year, day: Day) -> String {
+    format!("{}/{day}.txt", get_data_dir("inputs", year))
+}
+
+/// Path of a downloaded puzzle description, e.g. `data/2025/puzzles/01.md`.
+pub fn get_puzzle_path(year: Year, day: Day) -> String {
+    format!("{}/{day}.md", get_data_dir("puzzles", year))
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Returns the first element starting with `open` up to and including `close`, or the whole input.
+fn first_element<'a>(html: &'a str, open: &str, close: &str) -> &'a str {
+    html.find(open)
+        .and_then(|start| {
+            let end = html[start..].find(close)? + start + close.len();
+            Some(&html[start..end])
+        })
+        .unwrap_or(html)
+}
+
+/// Converts the puzzle descriptions (one `<article>` per unlocked part) of a day page to markdown.
+/// Paragraphs outside of the articles, like "Your puzzle answer was ...", are kept as well.
+fn puzzle_to_markdown(html: &str) -> String {
+    let start = html.find("<article").unwrap_or(0);
+    let article_end = html
+        .rfind("</article>")
+        .map_or(html.len(), |i| i + "</article>".len());
+    let answer_end = html.rfind("Your puzzle answer was").and_then(|i| {
+        let end = html[i..].find("</p>")?;
+        Some(i + end + "</p>".len())
+    });
+    let end = answer_end.map_or(article_end, |end| end.max(article_end));
+
+    let mut markdown = html_to_markdown(&html[start..end.max(start)])
+        .trim()
+        .to_string();
+    markdown.push('\n');
+    markdown
+}
+
+/// Converts the small subset of HTML used in puzzle descriptions to markdown.
+fn html_to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut rest = html;
+    let mut in_pre = false;
+    let mut links: Vec<String> = vec![];
+
+    while let Some(start) = rest.find('<') {
+        push_text(&mut out, &rest[..start]);
+
+        let Some(end) = rest[start..].find('>') else {
+            rest = &rest[start..];
+            break;
+        };
+
+        let tag = &rest[start + 1..start + end];
+        rest = &rest[start + end + 1..];
+
+        let closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default();
+
+        match (name, closing) {
+            ("h2", false) => out.push_str("## "),
+            ("h2" | "p", true) => out.push_str("\n\n"),
+            ("pre", false) => {
+                in_pre = true;
+                out.push_str("```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+            }
+            ("code", _) if !in_pre => out.push('`'),
+            ("em", _) if !in_pre => out.push('*'),
+            ("li", false) => out.push_str("- "),
+            ("li", true) => out.push('\n'),
+            ("ul", true) => out.push('\n'),
+            ("a", false) => {
+                links.push(attribute(tag, "href").unwrap_or_default().to_string());
+                out.push('[');
+            }
+            ("a", true) => {
+                let href = links.pop().unwrap_or_default();
+                out.push_str(&format!("]({href})"));
+            }
+            _ => {}
+        }
+    }
+
+    push_text(&mut out, rest);
+    out
+}
+
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = tag[start..].find('"')? + start;
+    Some(&tag[start..end])
+}
+
+fn push_text(out: &mut String, text: &str) {
+    out.push_str(
+        &text
+            .replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&quot;", "\"")
+            .replace("&#39;", "'")
+            .replace("&amp;", "&"),
+    );
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{AocClient, AocCommandError, html_to_markdown, parse_title, puzzle_to_markdown};
+    use crate::{day, year};
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+    };
+
+    /// Serves one canned response per request on a local port and reports each raw request back.
+    fn stub_server(responses: Vec<(u16, &'static str)>) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                let mut content_length = 0;
+
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = len.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+
+                let mut content = vec![0; content_length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+                tx.send(request).unwrap();
+
+                let response = format!(
+                    "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                reader.get_mut().write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        (base_url, rx)
+    }
+
+    #[test]
+    fn downloads_input_with_session() {
+        let (base_url, requests) = stub_server(vec![(200, "1\n2\n3\n")]);
+        let client = AocClient::new(&base_url, "secret\n");
+
+        let input = client.input(year!(2025), day!(3)).unwrap();
+        assert_eq!(input, "1\n2\n3\n");
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /2025/day/3/input HTTP/1.1"));
+        assert!(request.contains("session=secret\r\n"));
+    }
+
+    #[test]
+    fn posts_answers() {
+        let (base_url, requests) = stub_server(vec![(
+            200,
+            "<main><article><p>That's the right answer!</p></article></main>",
+        )]);
+        let client = AocClient::new(&base_url, "secret");
+
+        let message = client.submit(year!(2025), day!(1), 2, "42").unwrap();
+        assert_eq!(message, "That's the right answer!");
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("POST /2025/day/1/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=42"));
+    }
+
+    #[test]
+    fn reports_rate_limiting() {
+        let (base_url, _requests) = stub_server(vec![
+            (
+                200,
+                "<article><p>You gave an answer too recently; you have 42s left to wait.</p></article>",
+            ),
+            (429, ""),
+        ]);
+        let client = AocClient::new(&base_url, "secret");
+
+        let result = client.submit(year!(2025), day!(1), 1, "42");
+        assert!(matches!(result, Err(AocCommandError::RateLimited(m)) if m.contains("42s left")));
+
+        let result = client.input(year!(2025), day!(1));
+        assert!(matches!(result, Err(AocCommandError::RateLimited(_))));
+    }
+
+    #[test]
+    fn reports_http_status() {
+        let (base_url, _requests) = stub_server(vec![(404, "not found")]);
+        let client = AocClient::new(&base_url, "secret");
+
+        let result = client.puzzle(year!(2025), day!(12));
+        assert!(matches!(result, Err(AocCommandError::HttpStatus(404))));
+    }
+
+    #[test]
+    fn converts_html_to_markdown() {
+        let html = r#"<h2>--- Day 1: Test ---</h2><p>Read <a href="/about">this</a>, the answer is <code><em>42</em></code>.</p><pre><code>a &lt; b
+<em>c</em>
+</code></pre><ul><li>one</li><li>two</li></ul>"#;
+        let expected = "## --- Day 1: Test ---\n\nRead [this](/about), the answer is `*42*`.\n\n```\na < b\nc\n```\n\n- one\n- two\n\n";
+        assert_eq!(html_to_markdown(html), expected);
+    }
+
+    #[test]
+    fn parses_puzzle_titles() {
+        assert_eq!(
+            parse_title("## --- Day 8: Playground ---\n\nEquipped with...\n"),
+            Some("Day 8: Playground".into())
+        );
+        assert_eq!(parse_title("no heading\n"), None);
+    }
+
+    #[test]
+    fn extracts_puzzle_descriptions() {
+        let html = r#"<html><body><header>nav</header><main>
+<article class="day-desc"><h2>--- Day 1 ---</h2><p>Part one.</p></article>
+<p>Your puzzle answer was <code>7</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Part two.</p></article>
+<form>answer form</form>
+</main></body></html>"#;
+        let markdown = puzzle_to_markdown(html);
+        assert!(markdown.starts_with("## --- Day 1 ---"));
+        assert!(markdown.contains("Your puzzle answer was `7`."));
+        assert!(markdown.contains("## --- Part Two ---\n\nPart two."));
+        assert!(!markdown.contains("answer form"));
+        assert!(!markdown.contains("nav"));
+    }
+}
@@ -0,0 +1,219 @@
+/// Module that renders the benchmark results as an SVG bar chart for the readme.
+use std::fmt::Write;
+
+use crate::template::Year;
+
+use super::timings::{Statistic, Timings};
+
+pub const CHART_PATH: &str = ".assets/benchmarks.svg";
+
+const WIDTH: f64 = 800_f64;
+const LABEL_WIDTH: f64 = 110_f64;
+const VALUE_WIDTH: f64 = 80_f64;
+const TOP: f64 = 60_f64;
+const BOTTOM: f64 = 20_f64;
+const ROW_HEIGHT: f64 = 22_f64;
+const BAR_HEIGHT: f64 = 16_f64;
+
+/// A bar of the chart: the time of one part in nanoseconds.
+struct Bar {
+    day: u8,
+    part: u8,
+    nanos: f64,
+}
+
+/// Render one horizontal bar per part on a logarithmic time axis, colored by day.
+pub fn render(year: Year, timings: &Timings, statistic: Statistic) -> String {
+    let bars: Vec<Bar> = timings
+        .data
+        .iter()
+        .flat_map(|timing| {
+            [1, 2].into_iter().filter_map(move |part| {
+                Some(Bar {
+                    day: timing.day.into_inner(),
+                    part,
+                    // a bar needs a positive length on a log scale.
+                    nanos: timing.part_nanos_by(part, statistic)?.max(1_f64),
+                })
+            })
+        })
+        .collect();
+
+    let (lo, hi) = axis_range(&bars);
+    let plot_width = WIDTH - LABEL_WIDTH - VALUE_WIDTH;
+    let x = |nanos: f64| LABEL_WIDTH + (nanos.log10() - lo) / (hi - lo) * plot_width;
+
+    #[allow(clippy::cast_precision_loss)]
+    let height = TOP + bars.len() as f64 * ROW_HEIGHT + BOTTOM;
+
+    let suffix = match statistic {
+        Statistic::Mean => "",
+        Statistic::Median => " (median)",
+    };
+
+    let mut svg = String::new();
+
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{WIDTH}" height="{height}" viewBox="0 0 {WIDTH} {height}" font-family="sans-serif" font-size="12">"#
+    );
+    let _ = writeln!(
+        svg,
+        r#"<rect width="{WIDTH}" height="{height}" fill="white"/>"#
+    );
+    let _ = writeln!(
+        svg,
+        r#"<text x="{LABEL_WIDTH}" y="20" font-size="14" font-weight="bold">Advent of Code {year} benchmarks{suffix}</text>"#
+    );
+
+    // one grid line per power of ten.
+    #[allow(clippy::cast_possible_truncation)]
+    for exponent in (lo as i32)..=(hi as i32) {
+        let gx = x(10_f64.powi(exponent));
+        let _ = writeln!(
+            svg,
+            r##"<line x1="{gx:.1}" y1="{}" x2="{gx:.1}" y2="{}" stroke="#ddd"/>"##,
+            TOP - 10_f64,
+            height - BOTTOM
+        );
+        let _ = writeln!(
+            svg,
+            r##"<text x="{gx:.1}" y="{}" text-anchor="middle" fill="#666">{}</text>"##,
+            TOP - 16_f64,
+            format_decade(exponent)
+        );
+    }
+
+    for (i, bar) in bars.iter().enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let y = TOP + i as f64 * ROW_HEIGHT;
+        let text_y = y + BAR_HEIGHT - 4_f64;
+        let end = x(bar.nanos);
+
+        let _ = writeln!(
+            svg,
+            r#"<text x="{}" y="{text_y}" text-anchor="end">Day {} · Part {}</text>"#,
+            LABEL_WIDTH - 8_f64,
+            bar.day,
+            bar.part
+        );
+        let _ = writeln!(
+            svg,
+            r#"<rect x="{LABEL_WIDTH}" y="{y}" width="{:.1}" height="{BAR_HEIGHT}" fill="{}"/>"#,
+            end - LABEL_WIDTH,
+            day_color(bar.day, year.day_count())
+        );
+        let _ = writeln!(
+            svg,
+            r#"<text x="{:.1}" y="{text_y}">{}</text>"#,
+            end + 6_f64,
+            super::timings::format_nanos(bar.nanos)
+        );
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Powers of ten around the shortest and longest bar, at least one decade apart.
+fn axis_range(bars: &[Bar]) -> (f64, f64) {
+    let logs = bars.iter().map(|bar| bar.nanos.log10());
+    let min = logs.clone().fold(f64::INFINITY, f64::min);
+    let max = logs.fold(f64::NEG_INFINITY, f64::max);
+
+    if bars.is_empty() {
+        // 1µs to 1s.
+        return (3_f64, 9_f64);
+    }
+
+    let lo = min.floor();
+    let hi = max.ceil().max(lo + 1_f64);
+    (lo, hi)
+}
+
+/// Label of a power of ten nanoseconds, e.g. `100µs`.
+fn format_decade(exponent: i32) -> String {
+    let (unit, offset) = match exponent {
+        ..3 => ("ns", 0),
+        3..6 => ("µs", 3),
+        6..9 => ("ms", 6),
+        _ => ("s", 9),
+    };
+
+    format!(
+        "{}{unit}",
+        10_u64.pow((exponent - offset).max(0).cast_unsigned())
+    )
+}
+
+/// Spread the days of the year around the color wheel.
+fn day_color(day: u8, day_count: u8) -> String {
+    let hue = u32::from(day.saturating_sub(1)) * 360 / u32::from(day_count.max(1));
+    format!("hsl({hue}, 65%, 55%)")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{day_color, format_decade, render};
+    use crate::template::Day;
+    use crate::template::timings::{Statistic, Timing, Timings};
+    use crate::year;
+
+    fn timing(day: u8, part_1: &str, part_2: Option<&str>) -> Timing {
+        Timing {
+            day: Day::new(day).unwrap(),
+            parse: None,
+            part_1: Some(part_1.into()),
+            part_2: part_2.map(str::to_string),
+            total_nanos: 0_f64,
+            stats: [None, None],
+            policy: None,
+            memory: [None, None],
+            errors: [None, None],
+        }
+    }
+
+    #[test]
+    fn formats_decades() {
+        assert_eq!(format_decade(0), "1ns");
+        assert_eq!(format_decade(2), "100ns");
+        assert_eq!(format_decade(4), "10µs");
+        assert_eq!(format_decade(6), "1ms");
+        assert_eq!(format_decade(10), "10s");
+    }
+
+    #[test]
+    fn colors_days() {
+        assert_eq!(day_color(1, 12), "hsl(0, 65%, 55%)");
+        assert_eq!(day_color(4, 12), "hsl(90, 65%, 55%)");
+    }
+
+    #[test]
+    fn renders_one_bar_per_part() {
+        let timings = Timings {
+            data: vec![
+                timing(1, "10.0µs", Some("1.0ms")),
+                timing(2, "100.0ms", None),
+            ],
+        };
+
+        let svg = render(year!(2025), &timings, Statistic::Mean);
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("<rect ").count(), 1 + 3);
+        assert!(svg.contains(">Day 2 · Part 1</text>"));
+        assert!(!svg.contains(">Day 2 · Part 2</text>"));
+        // the axis spans the decades from 10µs to 100ms.
+        assert!(svg.contains(">10µs</text>"));
+        assert!(svg.contains(">100ms</text>"));
+        assert!(!svg.contains(">1s</text>"));
+    }
+
+    #[test]
+    fn renders_empty_chart() {
+        let svg = render(year!(2025), &Timings::default(), Statistic::Mean);
+        assert_eq!(svg.matches("<rect ").count(), 1);
+    }
+}
@@ -1,5 +1,29 @@
-use crate::template::{all_days, run_multi::run_multi};
+use crate::template::run_multi::{Limits, RunOptions, run_multi};
+use crate::template::timings::BenchPolicy;
+use crate::template::{Format, Year, registry};
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+/// Runs every day of the year, `jobs` days at once.
+/// With `bench`, every part is benched according to the policy. Each day runs under `limits`.
+pub fn handle(
+    year: Year,
+    is_release: bool,
+    is_example: bool,
+    jobs: usize,
+    format: Option<Format>,
+    bench: Option<BenchPolicy>,
+    limits: Limits,
+) {
+    let options = RunOptions {
+        is_release,
+        is_example,
+        is_timed: bench.is_some(),
+        jobs,
+        format,
+        bench: bench.unwrap_or_default(),
+        limits,
+        ..RunOptions::default()
+    };
+
+    registry::switch_to_main_binary(&options);
+    run_multi(year, &year.days().collect(), &options);
 }
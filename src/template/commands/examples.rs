@@ -0,0 +1,155 @@
+use std::{fs, path::Path, process};
+
+use crate::template::puzzle::{
+    CodeBlock, code_blocks, default_examples, expected_answers, fill_expected_answers,
+    is_part_two_example,
+};
+use crate::template::{Day, Year, aoc_client, get_data_dir, get_path_for_bin};
+
+/// Options of the `examples` command.
+#[derive(Clone, Debug, Default)]
+pub struct ExamplesOptions {
+    /// Print the code blocks of the puzzle instead of writing examples.
+    pub list: bool,
+    /// 1-based indices of the code blocks to write, in order. Picks the blocks after "For example" if empty.
+    pub blocks: Vec<usize>,
+    /// Replace example files that are not empty.
+    pub overwrite: bool,
+}
+
+pub fn handle(year: Year, day: Day, options: &ExamplesOptions) {
+    let puzzle_path = aoc_client::get_puzzle_path(year, day);
+
+    let Ok(markdown) = fs::read_to_string(&puzzle_path) else {
+        eprintln!("No puzzle found at \"{puzzle_path}\". Type `cargo download {day}` first.");
+        process::exit(1);
+    };
+
+    let blocks = code_blocks(&markdown);
+    let defaults = default_examples(&blocks);
+
+    if options.list {
+        list(&blocks, &defaults);
+        return;
+    }
+
+    let selected = if options.blocks.is_empty() {
+        defaults
+    } else {
+        options
+            .blocks
+            .iter()
+            .map(|&n| {
+                if n == 0 || n > blocks.len() {
+                    eprintln!(
+                        "No code block {n}, the puzzle has {} code blocks.",
+                        blocks.len()
+                    );
+                    process::exit(1);
+                }
+                n - 1
+            })
+            .collect()
+    };
+
+    if selected.is_empty() {
+        eprintln!(
+            "No code block follows \"For example\". Type `cargo examples {day} --list` and pick one with `--block <n>`."
+        );
+        process::exit(1);
+    }
+
+    let example_dir = get_data_dir("examples", year);
+
+    if let Err(e) = fs::create_dir_all(&example_dir) {
+        eprintln!("Failed to create data directory: {e}");
+        process::exit(1);
+    }
+
+    for (i, &block) in selected.iter().enumerate() {
+        // the first example is the default one, further ones are numbered like `NN-2.txt`.
+        let file_name = if i == 0 {
+            format!("{day}.txt")
+        } else {
+            format!("{day}-{}.txt", i + 1)
+        };
+        let path = Path::new(&example_dir).join(file_name);
+
+        let is_empty = fs::read_to_string(&path).map_or(true, |s| s.trim().is_empty());
+        if !is_empty && !options.overwrite {
+            println!(
+                "Skipped \"{}\", it is not empty. Pass `--overwrite` to replace it.",
+                path.display()
+            );
+            continue;
+        }
+
+        match fs::write(&path, &blocks[block].text) {
+            Ok(()) => println!("Wrote code block {} to \"{}\"", block + 1, path.display()),
+            Err(e) => {
+                eprintln!("Failed to write example file: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    update_tests(year, day);
+}
+
+/// Fill the answers highlighted in the downloaded puzzle into the example tests of a day.
+/// Does nothing if the puzzle or the module does not exist, or there is nothing left to fill in.
+pub fn update_tests(year: Year, day: Day) {
+    let Ok(markdown) = fs::read_to_string(aoc_client::get_puzzle_path(year, day)) else {
+        return;
+    };
+
+    let module_path = get_path_for_bin(year, day);
+    let Ok(source) = fs::read_to_string(&module_path) else {
+        return;
+    };
+
+    // only a second example from part two's description belongs to part two's test.
+    let part_two_example =
+        fs::read_to_string(Path::new(&get_data_dir("examples", year)).join(format!("{day}-2.txt")))
+            .is_ok_and(|example| is_part_two_example(&markdown, &example));
+
+    let (source, parts) =
+        fill_expected_answers(&source, &expected_answers(&markdown), part_two_example);
+
+    if parts.is_empty() {
+        return;
+    }
+
+    match fs::write(&module_path, source) {
+        Ok(()) => {
+            for part in parts {
+                println!("Updated the example test of part {part} in \"{module_path}\"");
+            }
+        }
+        Err(e) => {
+            eprintln!("Failed to update tests: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Print every code block with its first lines. Blocks picked by default are marked with `*`.
+fn list(blocks: &[CodeBlock], defaults: &[usize]) {
+    const PREVIEW_LINES: usize = 3;
+
+    for (i, block) in blocks.iter().enumerate() {
+        let marker = if defaults.contains(&i) { "*" } else { " " };
+        let line_count = block.text.lines().count();
+
+        let lines = if line_count == 1 { "line" } else { "lines" };
+        println!("{marker} [{}] {line_count} {lines}", i + 1);
+
+        for line in block.text.lines().take(PREVIEW_LINES) {
+            println!("      {line}");
+        }
+
+        if line_count > PREVIEW_LINES {
+            println!("      ...");
+        }
+    }
+}
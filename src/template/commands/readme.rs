@@ -0,0 +1,24 @@
+use std::process;
+
+use crate::template::readme_benchmarks::Error;
+use crate::template::{Year, readme_stars};
+
+/// Updates the tables in the readme from local data, without talking to the server.
+pub fn handle(year: Year, stars: bool) {
+    if !stars {
+        eprintln!("Nothing to update. Pass `--stars` to fill the stars table.");
+        process::exit(1);
+    }
+
+    match readme_stars::update(year) {
+        Ok(count) => println!("Updated the stars table with {count} ⭐."),
+        Err(Error::Parser(e)) => {
+            eprintln!("Failed to update the stars table: {e}");
+            process::exit(1);
+        }
+        Err(Error::IO(_)) => {
+            eprintln!("Failed to update the stars table.");
+            process::exit(1);
+        }
+    }
+}
@@ -1,13 +1,18 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-use crate::template::Day;
+use crate::template::commands::examples;
+use crate::template::{Day, Year, aoc_client, get_data_dir, get_path_for_bin};
 
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+/// Directory of the module templates, one `<name>.txt` per template.
+const TEMPLATES_DIR: &str = "./templates";
+
+/// Template used when no `--template` is passed.
+pub const DEFAULT_TEMPLATE: &str = "default";
 
 fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
@@ -19,18 +24,72 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
     file.truncate(true).write(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
+/// Read a template from [`TEMPLATES_DIR`], listing the available ones if it does not exist.
+fn read_template(name: &str) -> Result<String, String> {
+    fs::read_to_string(Path::new(TEMPLATES_DIR).join(format!("{name}.txt"))).map_err(|e| {
+        let mut available: Vec<String> = fs::read_dir(TEMPLATES_DIR)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter_map(|entry| {
+                let path = entry.path();
+                (path.extension()? == "txt").then_some(path.file_stem()?.to_str()?.to_string())
+            })
+            .collect();
+        available.sort();
+
+        format!(
+            "Failed to read template `{name}`: {e}. Available templates: {}",
+            available.join(", ")
+        )
+    })
+}
+
+/// Fill in the placeholders of a template:
+///  - `%YEAR%` and `%DAY_NUMBER%`, e.g. `2025` and `8`.
+///  - `%TITLE%`, e.g. `Day 8: Playground` if the puzzle was downloaded, `Day 8` otherwise.
+///  - `%PUZZLE_URL%`, e.g. `https://adventofcode.com/2025/day/8`.
+fn render_template(template: &str, year: Year, day: Day, title: Option<&str>) -> String {
+    let day_number = day.into_inner().to_string();
+    let title = title.map_or_else(|| format!("Day {day_number}"), str::to_string);
+
+    template
+        .replace("%YEAR%", &year.to_string())
+        .replace("%DAY_NUMBER%", &day_number)
+        .replace("%TITLE%", &title)
+        .replace("%PUZZLE_URL%", &aoc_client::puzzle_url(year, day))
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// Create an empty data file, keeping the contents of an existing one (e.g. a downloaded input).
+fn create_data_file(path: &str, kind: &str) {
+    match OpenOptions::new().write(true).create_new(true).open(path) {
+        Ok(_) => println!("Created empty {kind} file \"{path}\""),
+        Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {}
+        Err(e) => {
+            eprintln!("Failed to create {kind} file: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+pub fn handle(year: Year, day: Day, overwrite: bool, template: &str) {
+    let input_dir = get_data_dir("inputs", year);
+    let example_dir = get_data_dir("examples", year);
+    let input_path = format!("{input_dir}/{day}.txt");
+    let example_path = format!("{example_dir}/{day}.txt");
+    let module_path = get_path_for_bin(year, day);
+
+    for dir in [&input_dir, &example_dir] {
+        if let Err(e) = fs::create_dir_all(dir) {
+            eprintln!("Failed to create data directory: {e}");
+            process::exit(1);
+        }
+    }
+
+    let template = read_template(template).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(1);
+    });
 
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
@@ -40,13 +99,11 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    let title = aoc_client::puzzle_title(year, day);
+
+    match file.write_all(render_template(&template, year, day, title.as_deref()).as_bytes()) {
         Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
+            println!("Created module file \"{module_path}\"");
         }
         Err(e) => {
             eprintln!("Failed to write module contents: {e}");
@@ -54,26 +111,63 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
-        }
+    create_data_file(&input_path, "input");
+    create_data_file(&example_path, "example");
+
+    examples::update_tests(year, day);
+
+    println!("---");
+    println!("🎄 Type `cargo solve {day} --year {year}` to run your solution.");
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{TEMPLATES_DIR, read_template, render_template};
+    use crate::template::puzzle::fill_expected_answers;
+    use crate::{day, year};
+    use std::fs;
+
+    #[test]
+    fn renders_placeholders() {
+        let template = "// %TITLE%\n// %PUZZLE_URL%\nsolution!(%YEAR%, %DAY_NUMBER%);";
+
+        assert_eq!(
+            render_template(template, year!(2025), day!(8), Some("Day 8: Playground")),
+            "// Day 8: Playground\n// https://adventofcode.com/2025/day/8\nsolution!(2025, 8);"
+        );
+        assert!(render_template(template, year!(2025), day!(8), None).starts_with("// Day 8\n"));
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
+    #[test]
+    fn fills_answers_of_every_template() {
+        let names = fs::read_dir(TEMPLATES_DIR)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+            .map(|path| path.file_stem().unwrap().to_str().unwrap().to_string());
+
+        for name in names {
+            let source =
+                render_template(&read_template(&name).unwrap(), year!(2025), day!(8), None);
+            let (filled, updated) =
+                fill_expected_answers(&source, &[Some("3".into()), Some("6".into())], true);
+
+            assert_eq!(updated, vec![1, 2], "template `{name}`");
+            assert!(
+                filled.contains("assert_eq!(result, Some(6));"),
+                "template `{name}`"
+            );
+
+            let (part_one, part_two) = filled.split_once("fn test_part_two()").unwrap();
+            assert!(!part_one.contains("read_file_part("), "template `{name}`");
+            assert_eq!(
+                part_two.matches("read_file_part(").count(),
+                1,
+                "template `{name}`"
+            );
+            assert!(part_two.contains("DAY, 2"), "template `{name}`");
         }
     }
-
-    println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
 }
@@ -1,30 +1,126 @@
-use std::process::{Command, Stdio};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
 
-use crate::template::Day;
+use crate::template::protocol::{RECORDS_ENV, RecordsFile};
+use crate::template::report::Report;
+use crate::template::run_multi::child_commands::{self, ChildOutput};
+use crate::template::timings::BenchPolicy;
+use crate::template::{
+    ANSI_ITALIC, ANSI_RESET, Day, Format, Year, get_bin_name, get_data_dir, get_path_for_bin,
+};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+/// How often watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
 
-    if dhat {
+/// Clears the terminal and moves the cursor to the top left.
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// Options of the `solve` command, most are passed through to the solution bin.
+#[derive(Clone, Debug, Default)]
+pub struct SolveOptions {
+    pub release: bool,
+    /// Bench the solution and print the sample distribution of each part.
+    pub time: bool,
+    /// How parts are benched with `time`.
+    pub bench: BenchPolicy,
+    pub dhat: bool,
+    pub submit_part: Option<u8>,
+    pub input: Option<String>,
+    pub example: Option<Option<u8>>,
+    /// Print a machine-readable report instead of the solution output.
+    pub format: Option<Format>,
+    /// Re-run the solution whenever its sources or data files change.
+    pub watch: bool,
+}
+
+pub fn handle(year: Year, day: Day, options: &SolveOptions) {
+    let mut cmd_args = vec![
+        "run".to_string(),
+        "--bin".to_string(),
+        get_bin_name(year, day),
+    ];
+
+    if options.dhat {
         cmd_args.extend([
             "--profile".to_string(),
             "dhat".to_string(),
             "--features".to_string(),
             "dhat-heap".to_string(),
         ]);
-    } else if release {
+    } else if options.release {
         cmd_args.push("--release".to_string());
     }
 
     cmd_args.push("--".to_string());
 
-    if let Some(submit_part) = submit_part {
+    if options.time {
+        cmd_args.push("--time".to_string());
+        cmd_args.extend(options.bench.to_args());
+    }
+
+    if let Some(submit_part) = options.submit_part {
         cmd_args.push("--submit".to_string());
         cmd_args.push(submit_part.to_string());
     }
 
+    if let Some(input) = &options.input {
+        cmd_args.push("--input".to_string());
+        cmd_args.push(input.clone());
+    }
+
+    if let Some(example) = options.example {
+        cmd_args.push("--example".to_string());
+        if let Some(n) = example {
+            cmd_args.push(n.to_string());
+        }
+    }
+
+    if let Some(format) = options.format {
+        let records = RecordsFile::new(year, day);
+
+        let output = Command::new("cargo")
+            .args(&cmd_args)
+            .env(RECORDS_ENV, records.path())
+            .stderr(Stdio::inherit())
+            .output()
+            .unwrap();
+
+        let output = ChildOutput {
+            stdout: String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .map(str::to_string)
+                .collect(),
+            stderr: vec![],
+            records: records.read(),
+            success: output.status.success(),
+            exceeded: None,
+        };
+
+        let report = Report {
+            year,
+            days: vec![child_commands::parse_report(&output, day)],
+        };
+
+        println!("{}", report.render(format));
+        return;
+    }
+
+    if options.watch {
+        watch(year, day, options, &cmd_args);
+    }
+
+    run(&cmd_args);
+}
+
+fn run(cmd_args: &[String]) {
     let mut cmd = Command::new("cargo")
-        .args(&cmd_args)
+        .args(cmd_args)
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
         .spawn()
@@ -32,3 +128,74 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
 
     cmd.wait().unwrap();
 }
+
+/// Run the solution, then rebuild and run it again on every change to one of the [`watched_paths`].
+fn watch(year: Year, day: Day, options: &SolveOptions, cmd_args: &[String]) -> ! {
+    let mut last_seen = None;
+
+    loop {
+        // taken before the run, so that files saved during a build trigger another run.
+        let seen = modification_times(&watched_paths(year, day, options));
+
+        if last_seen.as_ref() != Some(&seen) {
+            print!("{CLEAR_SCREEN}");
+            run(cmd_args);
+            println!("\n{ANSI_ITALIC}Watching for changes, press Ctrl+C to stop.{ANSI_RESET}");
+            last_seen = Some(seen);
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Files a run of the solution depends on: its bin, the library and template sources,
+/// the day's input and examples, and the file passed with `--input`.
+fn watched_paths(year: Year, day: Day, options: &SolveOptions) -> Vec<PathBuf> {
+    let mut paths = vec![
+        PathBuf::from(get_path_for_bin(year, day)),
+        PathBuf::from("./src/lib.rs"),
+        Path::new(&get_data_dir("inputs", year)).join(format!("{day}.txt")),
+    ];
+
+    collect_files(Path::new("./src/template"), &mut paths);
+
+    // numbered examples are named `NN-N.txt`.
+    if let Ok(entries) = fs::read_dir(get_data_dir("examples", year)) {
+        paths.extend(entries.flatten().map(|e| e.path()).filter(|path| {
+            path.file_stem()
+                .and_then(|stem| stem.to_str())
+                .is_some_and(|stem| stem.split('-').next() == Some(&day.to_string()))
+        }));
+    }
+
+    if let Some(input) = options.input.as_deref().filter(|input| *input != "-") {
+        paths.push(PathBuf::from(input));
+    }
+
+    paths
+}
+
+fn collect_files(dir: &Path, paths: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for path in entries.flatten().map(|e| e.path()) {
+        if path.is_dir() {
+            collect_files(&path, paths);
+        } else {
+            paths.push(path);
+        }
+    }
+}
+
+/// Modification time of every path that exists, so created and deleted files count as changes too.
+fn modification_times(paths: &[PathBuf]) -> BTreeMap<PathBuf, SystemTime> {
+    paths
+        .iter()
+        .filter_map(|path| {
+            let modified = fs::metadata(path).and_then(|m| m.modified()).ok()?;
+            Some((path.clone(), modified))
+        })
+        .collect()
+}
@@ -1,19 +1,83 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, process};
 
-use crate::template::run_multi::run_multi;
-use crate::template::timings::Timings;
-use crate::template::{Day, all_days, readme_benchmarks};
+use crate::template::compare::{self, Percentage};
+use crate::template::history::{History, Snapshot};
+use crate::template::run_multi::{Limits, RunOptions, run_multi};
+use crate::template::timings::{BenchPolicy, Statistic, Timings};
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, Format, Year, readme_benchmarks, registry};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+/// Options of the `time` command.
+#[derive(Clone, Debug)]
+pub struct TimeOptions {
+    pub run_all: bool,
+    pub store: bool,
+    pub jobs: usize,
+    pub bench: BenchPolicy,
+    /// Print a machine-readable report instead of the solution output.
+    pub format: Option<Format>,
+    /// Statistic shown in the readme table.
+    pub statistic: Statistic,
+    /// Baseline to compare the run against, see [`compare::load_baseline`].
+    pub compare: Option<String>,
+    /// Exit with an error if a part got slower than the baseline by more than this.
+    pub fail_above: Option<Percentage>,
+    /// Record the peak heap usage and allocation count of each part.
+    pub memory: bool,
+    /// Render the stored timings as a chart next to the readme table.
+    pub chart: bool,
+    /// Limits every day runs under.
+    pub limits: Limits,
+}
+
+impl Default for TimeOptions {
+    fn default() -> Self {
+        Self {
+            run_all: false,
+            store: false,
+            jobs: 1,
+            bench: BenchPolicy::default(),
+            format: None,
+            statistic: Statistic::Mean,
+            compare: None,
+            fail_above: None,
+            memory: false,
+            chart: false,
+            limits: Limits::default(),
+        }
+    }
+}
+
+pub fn handle(year: Year, day: Option<Day>, options: &TimeOptions) {
+    let stored_timings = Timings::read_from_file(year);
+
+    // refuse to run before a broken history would be replaced by this run alone.
+    let history = options.store.then(|| {
+        History::read_from_file(year).unwrap_or_else(|e| {
+            eprintln!("Failed to read benchmark history: {e}");
+            process::exit(1);
+        })
+    });
+
+    // `--fail-above` without a baseline compares against the stored timings.
+    let baseline = options
+        .compare
+        .as_deref()
+        .or(options.fail_above.map(|_| "stored"))
+        .map(|baseline| {
+            compare::load_baseline(year, baseline).unwrap_or_else(|e| {
+                eprintln!("{e}");
+                process::exit(1);
+            })
+        });
 
     let days_to_run = day.map_or_else(
         || {
-            if run_all {
-                all_days().collect()
+            // a comparison needs fresh timings of every day.
+            if options.run_all || baseline.is_some() {
+                year.days().collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
+                year.days()
                     .filter(|day| !stored_timings.is_day_complete(*day))
                     .collect()
             }
@@ -21,20 +85,141 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let format = options.format;
+
+    let run_options = RunOptions {
+        is_release: true,
+        is_timed: true,
+        jobs: options.jobs,
+        format,
+        bench: options.bench,
+        is_memory: options.memory,
+        limits: options.limits,
+        ..RunOptions::default()
+    };
+
+    registry::switch_to_main_binary(&run_options);
+    let timings = run_multi(year, &days_to_run, &run_options).timings();
 
-    if store {
+    let regressions = baseline.map_or(0, |baseline| {
+        print_comparison(&baseline, &timings, options.fail_above, format.is_none())
+    });
+
+    if options.store {
         let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        merged_timings.store_file(year).unwrap();
 
-        println!();
-        match readme_benchmarks::update(merged_timings) {
-            Ok(()) => {
+        if let Some(mut history) = history {
+            history.data.push(Snapshot::now(timings));
+            if let Err(e) = history.store_file(year) {
+                eprintln!("Failed to store benchmark history: {e}");
+            }
+        }
+
+        match readme_benchmarks::update(year, merged_timings, options.statistic, options.chart) {
+            // keep stdout parseable when a report was printed.
+            Ok(()) if format.is_none() => {
+                println!();
                 println!("Stored updated benchmarks.");
             }
+            Ok(()) => {}
             Err(_) => {
                 eprintln!("Failed to store updated benchmarks.");
             }
         }
     }
+
+    if regressions > 0 {
+        eprintln!(
+            "{regressions} part(s) regressed by more than {}.",
+            options.fail_above.unwrap()
+        );
+        process::exit(1);
+    }
+}
+
+/// Prints the change of every part against the baseline and returns the number of parts
+/// that regressed above the threshold. Goes to stderr when stdout holds a report.
+fn print_comparison(
+    baseline: &Timings,
+    timings: &Timings,
+    fail_above: Option<Percentage>,
+    to_stdout: bool,
+) -> usize {
+    let print = |line: String| {
+        if to_stdout {
+            println!("{line}");
+        } else {
+            eprintln!("{line}");
+        }
+    };
+
+    let diffs = compare::compare(baseline, timings);
+    let mut regressions = 0;
+
+    print(String::new());
+    print(format!("{ANSI_BOLD}Comparison{ANSI_RESET}"));
+    print("----------".into());
+
+    if diffs.is_empty() {
+        print("No parts to compare with the baseline.".into());
+    }
+
+    for diff in diffs {
+        let regressed = fail_above.is_some_and(|threshold| diff.regressed(threshold));
+        if regressed {
+            regressions += 1;
+        }
+
+        print(format!(
+            "Day {} {diff}{}",
+            diff.day,
+            if regressed { " ✘" } else { "" }
+        ));
+    }
+
+    regressions
+}
+
+/// Prints how the runtime of each part evolved across the stored benchmark runs.
+pub fn history(year: Year, day: Option<Day>) {
+    let history = History::read_from_file(year).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(1);
+    });
+    let days = day.map_or_else(|| history.days(), |day| vec![day]);
+
+    if history.data.is_empty() {
+        println!("No benchmark history yet. Run `cargo time --store` to record one.");
+        return;
+    }
+
+    for (i, day) in days.into_iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
+        println!(
+            "{:<14}  {:<10}  {:>10}  {:>10}",
+            "Commit", "Date", "Part 1", "Part 2"
+        );
+
+        let mut has_timings = false;
+        for (snapshot, timing) in history.for_day(day) {
+            has_timings = true;
+            println!(
+                "{:<14}  {:<10}  {:>10}  {:>10}",
+                snapshot.commit,
+                snapshot.date(),
+                timing.part_1.as_deref().unwrap_or("-"),
+                timing.part_2.as_deref().unwrap_or("-"),
+            );
+        }
+
+        if !has_timings {
+            println!("Not benched.");
+        }
+    }
 }
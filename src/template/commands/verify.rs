@@ -0,0 +1,82 @@
+use std::process;
+
+use crate::template::answers::Answers;
+use crate::template::run_multi::{self, RunOptions, child_commands};
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, Year, registry};
+
+/// Runs each solution on its real input and compares the answers with `data/answers.json`.
+/// Exits with a non-zero status if any part returns a different answer.
+pub fn handle(year: Year, days: &[Day], is_release: bool) {
+    let answers = Answers::read_from_file().unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(1);
+    });
+
+    let days_to_run: Vec<Day> = if days.is_empty() {
+        year.days().collect()
+    } else {
+        let mut days = days.to_vec();
+        days.sort_unstable();
+        days.dedup();
+        days
+    };
+
+    let options = RunOptions {
+        is_release,
+        ..RunOptions::default()
+    };
+
+    registry::switch_to_main_binary(&options);
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    for (i, day) in days_to_run.into_iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
+
+        let output = run_multi::run_solution(year, day, &options, false).unwrap();
+        output.stderr.iter().for_each(|line| eprintln!("{line}"));
+
+        if output.stdout.is_empty() {
+            println!("Not solved.");
+        }
+
+        let report = child_commands::parse_report(&output, day);
+        for (part, report) in [1, 2].into_iter().zip(report.parts) {
+            match (answers.get(year, day, part), report.answer) {
+                (Some(expected), Some(actual)) if expected == actual => {
+                    passed += 1;
+                    println!("Part {part}: ✔ {actual}");
+                }
+                (Some(expected), Some(actual)) => {
+                    failed += 1;
+                    println!("Part {part}: ✘ expected {expected}, got {actual}");
+                }
+                (Some(expected), None) => {
+                    failed += 1;
+                    match report.error {
+                        Some(error) => println!("Part {part}: ✘ expected {expected}, got {error}"),
+                        None => println!("Part {part}: ✘ expected {expected}, got no answer"),
+                    }
+                }
+                (None, _) if !output.stdout.is_empty() => {
+                    missing += 1;
+                    println!("Part {part}: - no stored answer");
+                }
+                (None, _) => missing += 1,
+            }
+        }
+    }
+
+    println!(
+        "\n{ANSI_BOLD}Verified:{ANSI_RESET} {passed} passed, {failed} failed, {missing} missing"
+    );
+
+    if failed > 0 {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,174 @@
+/// Module that compares a benchmark run against a baseline.
+use std::{fmt::Display, fs, path::Path, str::FromStr};
+
+use crate::template::{Day, Year};
+
+use super::history::History;
+use super::timings::{Timings, format_nanos};
+
+/// A relative change in percent, e.g. `10%`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Percentage(pub f64);
+
+impl FromStr for Percentage {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.trim_end_matches('%')
+            .parse::<f64>()
+            .ok()
+            .filter(|x| x.is_finite() && *x >= 0.0)
+            .map(Percentage)
+            .ok_or_else(|| format!("expecting a percentage like `10%`, got `{s}`"))
+    }
+}
+
+impl Display for Percentage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}%", self.0)
+    }
+}
+
+/// Runtime of one part in the baseline and in the current run, in nanoseconds.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartDiff {
+    pub day: Day,
+    pub part: u8,
+    pub baseline: f64,
+    pub current: f64,
+}
+
+impl PartDiff {
+    /// Relative change of the runtime in percent, positive if the part got slower.
+    pub fn change(&self) -> f64 {
+        (self.current - self.baseline) / self.baseline * 100_f64
+    }
+
+    /// Returns `true` if the part got slower by more than the threshold.
+    pub fn regressed(&self, threshold: Percentage) -> bool {
+        self.change() > threshold.0
+    }
+}
+
+impl Display for PartDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let change = self.change();
+        let direction = if change > 0_f64 { "slower" } else { "faster" };
+
+        write!(
+            f,
+            "Part {}: {} → {} ({change:+.1}%, {direction})",
+            self.part,
+            format_nanos(self.baseline),
+            format_nanos(self.current),
+        )
+    }
+}
+
+/// Pair up the parts that have a timing in both runs.
+pub fn compare(baseline: &Timings, current: &Timings) -> Vec<PartDiff> {
+    let mut diffs = vec![];
+
+    for timing in &current.data {
+        let Some(base) = baseline.data.iter().find(|t| t.day == timing.day) else {
+            continue;
+        };
+
+        for part in [1, 2] {
+            if let (Some(baseline), Some(current)) =
+                (base.part_nanos(part), timing.part_nanos(part))
+            {
+                diffs.push(PartDiff {
+                    day: timing.day,
+                    part,
+                    baseline,
+                    current,
+                });
+            }
+        }
+    }
+
+    diffs
+}
+
+/// Resolve a baseline: `stored` for `data/<year>/timings.json`, a path to a timings file,
+/// or a commit hash from the benchmark history.
+pub fn load_baseline(year: Year, baseline: &str) -> Result<Timings, String> {
+    if baseline == "stored" {
+        return Ok(Timings::read_from_file(year));
+    }
+
+    if Path::new(baseline).exists() {
+        return fs::read_to_string(baseline)
+            .map_err(|e| e.to_string())
+            .and_then(Timings::try_from)
+            .map_err(|e| format!("could not read baseline `{baseline}`: {e}"));
+    }
+
+    History::read_from_file(year)?
+        .data
+        .into_iter()
+        .rev()
+        .find(|snapshot| snapshot.commit.starts_with(baseline))
+        .map(|snapshot| snapshot.timings)
+        .ok_or_else(|| {
+            format!("no baseline `{baseline}`: expecting `stored`, a timings file or a commit in the benchmark history")
+        })
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Percentage, compare};
+    use crate::day;
+    use crate::template::timings::{Timing, Timings};
+
+    fn timings(day_1: [Option<&str>; 2]) -> Timings {
+        Timings {
+            data: vec![Timing {
+                day: day!(1),
+                parse: None,
+                part_1: day_1[0].map(str::to_string),
+                part_2: day_1[1].map(str::to_string),
+                total_nanos: 0_f64,
+                stats: [None, None],
+                memory: [None, None],
+                errors: [None, None],
+                policy: None,
+            }],
+        }
+    }
+
+    #[test]
+    fn parses_percentages() {
+        assert_eq!("10%".parse::<Percentage>(), Ok(Percentage(10.0)));
+        assert_eq!("2.5".parse::<Percentage>(), Ok(Percentage(2.5)));
+        assert!("-5%".parse::<Percentage>().is_err());
+        assert!("ten".parse::<Percentage>().is_err());
+    }
+
+    #[test]
+    fn compares_parts() {
+        let baseline = timings([Some("10.0ms"), Some("2.0ms")]);
+        let current = timings([Some("12.0ms"), None]);
+
+        let diffs = compare(&baseline, &current);
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(diffs[0].part, 1);
+        assert!((diffs[0].change() - 20.0).abs() < 1e-9);
+        assert!(diffs[0].regressed(Percentage(10.0)));
+        assert!(!diffs[0].regressed(Percentage(25.0)));
+        assert_eq!(
+            diffs[0].to_string(),
+            "Part 1: 10.0ms → 12.0ms (+20.0%, slower)"
+        );
+    }
+
+    #[test]
+    fn skips_days_missing_from_baseline() {
+        let baseline = Timings::default();
+        let current = timings([Some("1.0ms"), Some("1.0ms")]);
+        assert!(compare(&baseline, &current).is_empty());
+    }
+}
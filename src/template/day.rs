@@ -5,11 +5,16 @@ use std::str::FromStr;
 #[cfg(feature = "today")]
 use chrono::{Datelike, FixedOffset, Utc};
 
+use crate::template::Year;
+
 #[cfg(feature = "today")]
 const SERVER_UTC_OFFSET: i32 = -5;
 
-/// A valid day number of advent (i.e. an integer in range 1 to 12).
-/// Before the year 2025, allow integers up to 25.
+/// The highest day number of any year. Since 2025, events only run for 12 days.
+pub const MAX_DAY: u8 = 25;
+
+/// A valid day number of advent (i.e. an integer in range 1 to 25).
+/// Use [`Year::has_day`] to check whether the day is part of a specific year.
 ///
 /// # Display
 /// This value displays as a two digit number.
@@ -22,20 +27,11 @@ const SERVER_UTC_OFFSET: i32 = -5;
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Day(u8);
 
-macro_rules! day_count {
-    () => {
-        match const_str::parse!(env!("AOC_YEAR"), u16) < 2025 {
-            true => 25u8,
-            false => 12u8,
-        }
-    };
-}
-
 impl Day {
     /// Creates a [`Day`] from the provided value if it's in the valid range,
     /// returns [`None`] otherwise.
     pub const fn new(day: u8) -> Option<Self> {
-        if day == 0 || day > day_count!() {
+        if day == 0 || day > MAX_DAY {
             return None;
         }
         Some(Self(day))
@@ -49,13 +45,14 @@ impl Day {
 
 #[cfg(feature = "today")]
 impl Day {
-    /// Returns the current day if it's between the 1st and the 12th of december, `None` otherwise.
-    /// Accepts days up to the 25th if the Year is before 2025.
-    pub fn today() -> Option<Self> {
+    /// Returns the current year and day if it's an advent day of december, `None` otherwise.
+    /// Accepts days up to the 12th since 2025 and up to the 25th before.
+    pub fn today() -> Option<(Year, Self)> {
         let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
         let today = Utc::now().with_timezone(&offset);
-        if today.month() == 12 && today.day() <= day_count!() as u32 {
-            Self::new(u8::try_from(today.day()).ok()?)
+        let year = Year::new(u16::try_from(today.year()).ok()?)?;
+        if today.month() == 12 && today.day() <= u32::from(year.day_count()) {
+            Some((year, Self::new(u8::try_from(today.day()).ok()?)?))
         } else {
             None
         }
@@ -99,29 +96,29 @@ impl Error for DayFromStrError {}
 
 impl Display for DayFromStrError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str(&format!(
-            "expecting a day number between 1 and {}",
-            day_count!()
-        ))
+        f.write_str(&format!("expecting a day number between 1 and {MAX_DAY}"))
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
 /// An iterator that yields every day of advent from the 1st to the 12th (or 25th before 2025).
-pub fn all_days() -> AllDays {
-    AllDays::new()
+pub fn all_days(year: Year) -> AllDays {
+    AllDays::new(year)
 }
 
 /// An iterator that yields every day of advent from the 1st to the 12th (or 25th before 2025).
 pub struct AllDays {
     current: u8,
+    last: u8,
 }
 
 impl AllDays {
-    #[allow(clippy::new_without_default)]
-    pub fn new() -> Self {
-        Self { current: 1 }
+    pub fn new(year: Year) -> Self {
+        Self {
+            current: 1,
+            last: year.day_count(),
+        }
     }
 }
 
@@ -129,7 +126,7 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > day_count!() {
+        if self.current > self.last {
             return None;
         }
         // NOTE: the iterator starts at 1, and we have verified that the value is not above 12 (or 25).
@@ -147,9 +144,8 @@ impl Iterator for AllDays {
 macro_rules! day {
     ($day:expr) => {
         const {
-            $crate::template::Day::new($day).expect(
-                "invalid day number, expecting a value between 1 and 12 (or 25 before 2025)",
-            )
+            $crate::template::Day::new($day)
+                .expect("invalid day number, expecting a value between 1 and 25")
         }
     };
 }
@@ -159,10 +155,11 @@ macro_rules! day {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{Day, all_days};
+    use crate::year;
 
     #[test]
     fn all_days_iterator() {
-        let mut iter = all_days();
+        let mut iter = all_days(year!(2025));
 
         assert_eq!(iter.next(), Some(Day(1)));
         assert_eq!(iter.next(), Some(Day(2)));
@@ -176,23 +173,16 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(10)));
         assert_eq!(iter.next(), Some(Day(11)));
         assert_eq!(iter.next(), Some(Day(12)));
-        if day_count!() > 12 {
-            assert_eq!(iter.next(), Some(Day(13)));
-            assert_eq!(iter.next(), Some(Day(14)));
-            assert_eq!(iter.next(), Some(Day(15)));
-            assert_eq!(iter.next(), Some(Day(16)));
-            assert_eq!(iter.next(), Some(Day(17)));
-            assert_eq!(iter.next(), Some(Day(18)));
-            assert_eq!(iter.next(), Some(Day(19)));
-            assert_eq!(iter.next(), Some(Day(20)));
-            assert_eq!(iter.next(), Some(Day(21)));
-            assert_eq!(iter.next(), Some(Day(22)));
-            assert_eq!(iter.next(), Some(Day(23)));
-            assert_eq!(iter.next(), Some(Day(24)));
-            assert_eq!(iter.next(), Some(Day(25)));
-        }
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn all_days_iterator_before_2025() {
+        let days: Vec<Day> = all_days(year!(2024)).collect();
+        assert_eq!(days.len(), 25);
+        assert_eq!(days.first(), Some(&Day(1)));
+        assert_eq!(days.last(), Some(&Day(25)));
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -0,0 +1,294 @@
+/// Module that keeps every stored benchmark run, so runtimes can be compared across commits.
+use std::{
+    collections::HashMap,
+    fs,
+    io::{Error, ErrorKind},
+    path::Path,
+    process::Command,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, Year};
+
+use super::timings::{Timing, Timings};
+
+fn get_history_path(year: Year) -> String {
+    format!("./data/{year}/timings_history.json")
+}
+
+/// Timings measured by one `cargo time --store` run.
+#[derive(Clone, Debug)]
+pub struct Snapshot {
+    /// Short hash of the commit that was checked out, suffixed with `-dirty` for uncommitted changes.
+    pub commit: String,
+    /// Seconds since the UNIX epoch.
+    pub timestamp: u64,
+    pub timings: Timings,
+}
+
+impl Snapshot {
+    /// Creates a snapshot of the current commit, timestamped with the current time.
+    pub fn now(timings: Timings) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        Snapshot {
+            commit: current_commit(),
+            timestamp,
+            timings,
+        }
+    }
+
+    /// The date of the snapshot as `YYYY-MM-DD` (UTC).
+    pub fn date(&self) -> String {
+        format_date(self.timestamp)
+    }
+}
+
+/// Every stored benchmark run of a year, oldest first.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct History {
+    pub data: Vec<Snapshot>,
+}
+
+impl History {
+    /// Dehydrate history to a JSON file.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(get_history_path(year))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate history from a JSON file. If not present, returns an empty history.
+    /// A file that cannot be parsed is an error, so it is not overwritten and its runs are kept.
+    pub fn read_from_file(year: Year) -> Result<Self, String> {
+        Self::read_from_path(Path::new(&get_history_path(year)))
+    }
+
+    fn read_from_path(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(content) => History::try_from(content)
+                .map_err(|e| format!("could not parse `{}`: {e}", path.display())),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(History::default()),
+            Err(e) => Err(format!("could not read `{}`: {e}", path.display())),
+        }
+    }
+
+    /// Snapshots that contain a timing for the day, paired with that timing.
+    pub fn for_day(&self, day: Day) -> impl Iterator<Item = (&Snapshot, &Timing)> {
+        self.data.iter().filter_map(move |snapshot| {
+            snapshot
+                .timings
+                .data
+                .iter()
+                .find(|t| t.day == day)
+                .map(|timing| (snapshot, timing))
+        })
+    }
+
+    /// Days that have at least one timing in the history, sorted.
+    pub fn days(&self) -> Vec<Day> {
+        let mut days: Vec<Day> = self
+            .data
+            .iter()
+            .flat_map(|s| s.timings.data.iter().map(|t| t.day))
+            .collect();
+        days.sort_unstable();
+        days.dedup();
+        days
+    }
+}
+
+/// Short hash of `HEAD`, or `unknown` outside of a git repository.
+fn current_commit() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(hash) => match git(&["status", "--porcelain", "--untracked-files=no"]) {
+            Some(status) if !status.is_empty() => format!("{hash}-dirty"),
+            _ => hash,
+        },
+        None => "unknown".into(),
+    }
+}
+
+/// Format a UNIX timestamp as a `YYYY-MM-DD` date.
+/// see: http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+#[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+fn format_date(timestamp: u64) -> String {
+    let days = (timestamp / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<History> for JsonValue {
+    fn from(value: History) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for History {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(History {
+            data: json_data
+                .iter()
+                .map(Snapshot::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Snapshot> for JsonValue {
+    fn from(value: &Snapshot) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("commit".into(), JsonValue::String(value.commit.clone()));
+        map.insert("date".into(), JsonValue::String(value.date()));
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert(
+            "timings".into(),
+            JsonValue::Array(value.timings.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Snapshot {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected snapshot to be a JSON object.")?;
+
+        let commit = json
+            .get("commit")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected snapshot.commit to be a string.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|&t| t as u64)
+            .ok_or("Expected snapshot.timestamp to be a number.")?;
+
+        let timings = json
+            .get("timings")
+            .and_then(|v| v.get::<Vec<JsonValue>>())
+            .ok_or("Expected snapshot.timings to be an array.")?;
+
+        Ok(Snapshot {
+            commit: commit.clone(),
+            timestamp,
+            timings: Timings {
+                data: timings
+                    .iter()
+                    .map(Timing::try_from)
+                    .collect::<Result<_, _>>()?,
+            },
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{History, format_date};
+    use crate::day;
+    use std::{env, fs, process};
+
+    #[test]
+    fn formats_dates() {
+        assert_eq!(format_date(0), "1970-01-01");
+        assert_eq!(format_date(951_782_400), "2000-02-29");
+        assert_eq!(format_date(1_764_547_200), "2025-12-01");
+    }
+
+    #[test]
+    fn handles_json_history() {
+        let json = r#"{ "data": [
+            { "commit": "a1b2c3d", "date": "2025-12-01", "timestamp": 1764547200, "timings": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000 }] },
+            { "commit": "e4f5a6b-dirty", "date": "2025-12-02", "timestamp": 1764633600, "timings": [{ "day": "02", "part_1": "2ms", "part_2": "3ms", "total_nanos": 5000000 }, { "day": "01", "part_1": "0.5ms", "part_2": null, "total_nanos": 500000 }] }
+        ] }"#.to_string();
+        let history = History::try_from(json).unwrap();
+
+        assert_eq!(history.days(), vec![day!(1), day!(2)]);
+
+        let day_1: Vec<_> = history.for_day(day!(1)).collect();
+        assert_eq!(day_1.len(), 2);
+        assert_eq!(day_1[0].0.commit, "a1b2c3d");
+        assert_eq!(day_1[1].0.date(), "2025-12-02");
+        assert_eq!(day_1[1].1.part_1, Some("0.5ms".into()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_history() {
+        let json = r#"{ "data": [{ "commit": "a1b2c3d", "timings": [] }] }"#.to_string();
+        History::try_from(json).unwrap();
+    }
+
+    #[test]
+    fn rejects_malformed_history() {
+        let path = env::temp_dir().join(format!("aoc-history-{}.json", process::id()));
+
+        let _ = fs::remove_file(&path);
+        assert!(History::read_from_path(&path).unwrap().data.is_empty());
+
+        fs::write(&path, "<<<<<<< HEAD\n{ \"data\": [] }").unwrap();
+        let result = History::read_from_path(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert!(result.unwrap_err().starts_with("could not parse"));
+    }
+}
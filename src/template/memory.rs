@@ -0,0 +1,252 @@
+/// Module that counts the heap allocations of a solution part, see `cargo time --memory`.
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    collections::HashMap,
+    fmt::Display,
+    sync::atomic::{AtomicBool, AtomicIsize, AtomicU64, Ordering::Relaxed},
+};
+use tinyjson::JsonValue;
+
+/// Only allocations made while counting is enabled are tracked, so benchmarks do not pay for it.
+static ENABLED: AtomicBool = AtomicBool::new(false);
+/// Bytes allocated since counting was enabled, minus the bytes freed. Can become negative when
+/// memory allocated before is freed.
+static CURRENT: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+
+/// Allocator that wraps the system allocator. It is only installed as the global allocator with
+/// the `count-memory` feature, which `cargo time --memory` enables for the solution bins.
+pub struct CountingAlloc;
+
+#[cfg(all(feature = "count-memory", not(feature = "dhat-heap")))]
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+#[cfg(feature = "dhat-heap")]
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
+
+#[allow(clippy::cast_possible_wrap)]
+fn record(allocated: isize, count: u64) {
+    let current = CURRENT.fetch_add(allocated, Relaxed) + allocated;
+    PEAK.fetch_max(current, Relaxed);
+    ALLOCATIONS.fetch_add(count, Relaxed);
+}
+
+#[allow(clippy::cast_possible_wrap)]
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() && ENABLED.load(Relaxed) {
+            record(layout.size() as isize, 1);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() && ENABLED.load(Relaxed) {
+            record(layout.size() as isize, 1);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        if ENABLED.load(Relaxed) {
+            record(-(layout.size() as isize), 0);
+        }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() && ENABLED.load(Relaxed) {
+            record(new_size as isize - layout.size() as isize, 1);
+        }
+        new_ptr
+    }
+}
+
+/// Heap usage of one run of a part.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MemoryUsage {
+    /// Most bytes the part held on the heap at once, on top of what was allocated before it ran.
+    pub peak_bytes: u64,
+    /// Number of allocations and reallocations.
+    pub allocations: u64,
+}
+
+impl Display for MemoryUsage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let unit = if self.allocations == 1 {
+            "allocation"
+        } else {
+            "allocations"
+        };
+
+        write!(
+            f,
+            "peak {}, {} {unit}",
+            format_bytes(self.peak_bytes),
+            self.allocations
+        )
+    }
+}
+
+/// Run a function and count its heap usage. Only counts if [`CountingAlloc`] is the global allocator.
+#[allow(clippy::cast_sign_loss)]
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, MemoryUsage) {
+    CURRENT.store(0, Relaxed);
+    PEAK.store(0, Relaxed);
+    ALLOCATIONS.store(0, Relaxed);
+
+    ENABLED.store(true, Relaxed);
+    let result = func();
+    ENABLED.store(false, Relaxed);
+
+    let usage = MemoryUsage {
+        peak_bytes: PEAK.load(Relaxed).max(0) as u64,
+        allocations: ALLOCATIONS.load(Relaxed),
+    };
+
+    (result, usage)
+}
+
+/// Format a number of bytes with a binary unit, e.g. `1.5 KiB`.
+#[allow(clippy::cast_precision_loss)]
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024_f64;
+    let mut unit = 0;
+
+    while value >= 1024_f64 && unit < UNITS.len() - 1 {
+        value /= 1024_f64;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/// Parse a number of bytes like `4096`, `512M` or `2GiB`. Units are binary, so `1K` is 1024 bytes.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn parse_bytes(s: &str) -> Result<u64, String> {
+    let error = || format!("expecting a size like `512M` or `2G`, got `{s}`");
+
+    let s = s.trim();
+    let (number, unit) = s.split_at(
+        s.find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(s.len()),
+    );
+
+    let number: f64 = number.parse().map_err(|_| error())?;
+    let exponent = match unit
+        .trim()
+        .trim_end_matches(['B', 'b'])
+        .trim_end_matches('i')
+        .to_ascii_uppercase()
+        .as_str()
+    {
+        "" => 0,
+        "K" => 1,
+        "M" => 2,
+        "G" => 3,
+        "T" => 4,
+        _ => return Err(error()),
+    };
+
+    Ok((number * 1024_f64.powi(exponent)) as u64)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[allow(clippy::cast_precision_loss)]
+impl From<MemoryUsage> for JsonValue {
+    fn from(value: MemoryUsage) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "peak_bytes".into(),
+            JsonValue::Number(value.peak_bytes as f64),
+        );
+        map.insert(
+            "allocations".into(),
+            JsonValue::Number(value.allocations as f64),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for MemoryUsage {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected memory to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .map(|n| n as u64)
+                .ok_or(format!("Expected memory.{key} to be a number."))
+        };
+
+        Ok(MemoryUsage {
+            peak_bytes: number("peak_bytes")?,
+            allocations: number("allocations")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{MemoryUsage, format_bytes, parse_bytes};
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+        assert_eq!(format_bytes(2 * 1024 * 1024 * 1024), "2.0 GiB");
+    }
+
+    #[test]
+    fn displays_memory_usage() {
+        let usage = MemoryUsage {
+            peak_bytes: 2048,
+            allocations: 1,
+        };
+        assert_eq!(usage.to_string(), "peak 2.0 KiB, 1 allocation");
+    }
+
+    #[test]
+    fn roundtrips_memory_usage() {
+        let usage = MemoryUsage {
+            peak_bytes: 123_456,
+            allocations: 789,
+        };
+        let json = tinyjson::JsonValue::from(usage);
+        assert_eq!(MemoryUsage::try_from(&json), Ok(usage));
+    }
+
+    #[test]
+    fn parses_bytes() {
+        assert_eq!(parse_bytes("4096"), Ok(4096));
+        assert_eq!(parse_bytes("512M"), Ok(512 * 1024 * 1024));
+        assert_eq!(parse_bytes("2GiB"), Ok(2 * 1024 * 1024 * 1024));
+        assert_eq!(parse_bytes("1.5k"), Ok(1536));
+        assert!(parse_bytes("lots").is_err());
+        assert!(parse_bytes("2X").is_err());
+    }
+}
@@ -1,68 +1,148 @@
 use std::{env, fs};
 
-pub mod aoc_cli;
+pub mod aoc_client;
 pub mod commands;
+pub mod memory;
+pub mod registry;
 pub mod runner;
+pub mod solution;
 
+pub use compare::Percentage;
 pub use day::*;
+pub use memory::parse_bytes;
+pub use report::Format;
+pub use run_multi::Limits;
+pub use solution::Solution;
+pub use timings::{BenchPolicy, Statistic, parse_duration};
+pub use year::*;
 
+mod answers;
+mod chart;
+mod compare;
 mod day;
+mod history;
+mod protocol;
+mod puzzle;
 mod readme_benchmarks;
+mod readme_stars;
+mod report;
 mod run_multi;
+mod submissions;
 mod timings;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// Path of a data folder (`inputs`, `examples` or `puzzles`) for a year, e.g. `data/2025/inputs`.
+#[must_use]
+pub fn get_data_dir(folder: &str, year: Year) -> String {
+    format!("data/{year}/{folder}")
+}
+
+/// Name of the solution binary for a day, e.g. `2025_01`.
+#[must_use]
+pub fn get_bin_name(year: Year, day: Day) -> String {
+    format!("{year}_{day}")
+}
+
+/// Path of the solution module for a day, e.g. `./src/bin/2025_01.rs`.
+#[must_use]
+pub fn get_path_for_bin(year: Year, day: Day) -> String {
+    format!("./src/bin/{}.rs", get_bin_name(year, day))
+}
+
 /// Helper function that reads a text file to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn read_file(folder: &str, year: Year, day: Day) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd
+        .join(get_data_dir(folder, year))
+        .join(format!("{day}.txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, year: Year, day: Day, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
-        .join("data")
-        .join(folder)
+        .join(get_data_dir(folder, year))
         .join(format!("{day}-{part}.txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constants `YEAR` and `DAY`, implements [`Solution`] for the day's functions and sets up the input and runner for each part.
+/// The implementation is returned by a generated `solution()` function.
+///
+/// The optional, third parameter (1 or 2) allows you to only run a single part of the solution.
+///
+/// With `parser = <fn>`, the input is parsed once and both parts receive a reference to the parsed value.
+/// The parse time is then reported separately from the part times. The parsed value must not borrow from the input.
 ///
-/// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// ```ignore
+/// advent_of_code::solution!(2025, 3, parser = parse);
+///
+/// fn parse(input: &str) -> Vec<u32> { /* ... */ }
+/// pub fn part_one(batteries: &[u32]) -> Option<u32> { /* ... */ }
+/// pub fn part_two(batteries: &[u32]) -> Option<u64> { /* ... */ }
+/// ```
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+    ($year:expr, $day:expr) => {
+        $crate::solution!(@impl $year, $day, [1, 2], part_one, part_two);
+    };
+    ($year:expr, $day:expr, 1) => {
+        $crate::solution!(@impl $year, $day, [1], part_one, $crate::template::solution::unsolved);
+    };
+    ($year:expr, $day:expr, 2) => {
+        $crate::solution!(@impl $year, $day, [2], $crate::template::solution::unsolved, part_two);
     };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+    ($year:expr, $day:expr, parser = $parser:expr) => {
+        // the closures let a part take e.g. `&[T]` for a parsed `Vec<T>`.
+        $crate::solution!(@parsed $year, $day, $parser, [1, 2], |p| part_one(p), |p| part_two(p));
     };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+    ($year:expr, $day:expr, 1, parser = $parser:expr) => {
+        $crate::solution!(@parsed $year, $day, $parser, [1], |p| part_one(p), $crate::template::solution::unsolved);
+    };
+    ($year:expr, $day:expr, 2, parser = $parser:expr) => {
+        $crate::solution!(@parsed $year, $day, $parser, [2], $crate::template::solution::unsolved, |p| part_two(p));
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
-        /// The current day.
-        const DAY: $crate::template::Day = $crate::day!($day);
+    (@impl $year:expr, $day:expr, [$($part:expr),*], $part_one:expr, $part_two:expr) => {
+        $crate::solution!(@consts $year, $day);
+
+        /// The parts of this day as a `Solution`.
+        pub fn solution() -> impl $crate::template::Solution {
+            $crate::template::solution::Parts::new(YEAR, DAY, $part_one, $part_two)
+        }
+
+        pub fn main() {
+            $crate::template::runner::run_parts(&solution(), &[$($part),*]);
+        }
+    };
 
-        #[cfg(feature = "dhat-heap")]
-        #[global_allocator]
-        static ALLOC: dhat::Alloc = dhat::Alloc;
+    (@parsed $year:expr, $day:expr, $parser:expr, [$($part:expr),*], $part_one:expr, $part_two:expr) => {
+        $crate::solution!(@consts $year, $day);
 
-        fn main() {
-            use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+        /// The parser and parts of this day as a `Solution`.
+        pub fn solution() -> impl $crate::template::Solution {
+            $crate::template::solution::ParsedParts::new(YEAR, DAY, $parser, $part_one, $part_two)
         }
+
+        pub fn main() {
+            $crate::template::runner::run_parsed(&solution(), &[$($part),*]);
+        }
+    };
+
+    (@consts $year:expr, $day:expr) => {
+        /// The year of the event.
+        const YEAR: $crate::template::Year = $crate::year!($year);
+
+        /// The current day.
+        const DAY: $crate::template::Day = $crate::day!($day);
     };
 }
@@ -0,0 +1,367 @@
+/// Module for the records a solution bin reports to the command that spawned it.
+///
+/// The human-readable output of a solution bin is meant for the terminal. When a command needs
+/// the answers and timings, it passes a file in `AOC_RECORDS` and the bin appends one JSON
+/// object per line to it, next to printing its output as usual. Solutions that run in the main
+/// binary hand their records over directly, see [`collect`].
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    env,
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    process,
+    str::FromStr,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, Year};
+
+use super::memory::MemoryUsage;
+use super::timings::Stats;
+
+/// Environment variable that holds the path of the records file.
+pub const RECORDS_ENV: &str = "AOC_RECORDS";
+
+/// Duration of a parser or a part in nanoseconds. Benched runs report the mean and the
+/// [`Stats`] of their samples, runs with `--memory` the heap usage of the first run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Measurement {
+    pub nanos: f64,
+    pub samples: u128,
+    pub stats: Option<Stats>,
+    pub memory: Option<MemoryUsage>,
+}
+
+/// A line in the records file.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Record {
+    /// The parser shared by both parts ran.
+    Parse(Measurement),
+    /// A part ran, `answer` is `None` if it returned no result. `error` is the message of a
+    /// part that returned an error.
+    Part {
+        part: u8,
+        answer: Option<String>,
+        error: Option<String>,
+        measurement: Measurement,
+    },
+}
+
+thread_local! {
+    /// Records of a solution that runs in the main binary, see [`collect`].
+    static COLLECTED: RefCell<Option<Vec<Record>>> = const { RefCell::new(None) };
+}
+
+/// Run a solution on the current thread and return the records it emitted.
+pub fn collect<T>(func: impl FnOnce() -> T) -> (T, Vec<Record>) {
+    let previous = COLLECTED.replace(Some(vec![]));
+    let result = func();
+    let records = COLLECTED.replace(previous).unwrap_or_default();
+    (result, records)
+}
+
+/// Append a record to the records file, if the bin was asked for records.
+pub fn emit(record: &Record) {
+    let is_collected = COLLECTED.with_borrow_mut(|collected| {
+        collected
+            .as_mut()
+            .map(|records| records.push(record.clone()))
+            .is_some()
+    });
+
+    if is_collected {
+        return;
+    }
+
+    let Some(path) = env::var_os(RECORDS_ENV) else {
+        return;
+    };
+
+    let line = JsonValue::from(record).stringify().unwrap();
+
+    let result = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| writeln!(file, "{line}"));
+
+    if let Err(e) = result {
+        eprintln!("failed to write record: {e}");
+    }
+}
+
+/// A records file for one run of a solution bin, removed when dropped.
+#[derive(Debug)]
+pub struct RecordsFile {
+    path: PathBuf,
+}
+
+impl RecordsFile {
+    /// Creates a path in the temporary directory that is unique to this process and run.
+    pub fn new(year: Year, day: Day) -> Self {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = env::temp_dir().join(format!("aoc-{year}-{day}-{}-{n}.jsonl", process::id()));
+
+        // a leftover from an earlier process with the same id would be read as ours.
+        let _ = fs::remove_file(&path);
+
+        RecordsFile { path }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Read the records written so far. Malformed lines are reported and skipped.
+    pub fn read(&self) -> Vec<Record> {
+        fs::read_to_string(&self.path)
+            .map(|content| parse_records(&content))
+            .unwrap_or_default()
+    }
+}
+
+impl Drop for RecordsFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+fn parse_records(content: &str) -> Vec<Record> {
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| match Record::from_str(line) {
+            Ok(record) => Some(record),
+            Err(e) => {
+                eprintln!("Could not parse record `{line}`: {e}");
+                None
+            }
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Record> for JsonValue {
+    fn from(value: &Record) -> Self {
+        let (kind, measurement) = match value {
+            Record::Parse(measurement) => ("parse", measurement),
+            Record::Part { measurement, .. } => ("part", measurement),
+        };
+
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("kind".into(), JsonValue::String(kind.into()));
+        map.insert("nanos".into(), JsonValue::Number(measurement.nanos));
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "samples".into(),
+            JsonValue::Number(measurement.samples as f64),
+        );
+        map.insert(
+            "stats".into(),
+            measurement.stats.map_or(JsonValue::Null, JsonValue::from),
+        );
+        map.insert(
+            "memory".into(),
+            measurement.memory.map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        if let Record::Part {
+            part,
+            answer,
+            error,
+            ..
+        } = value
+        {
+            map.insert("part".into(), JsonValue::Number(f64::from(*part)));
+            map.insert(
+                "answer".into(),
+                answer.clone().map_or(JsonValue::Null, JsonValue::String),
+            );
+            map.insert(
+                "error".into(),
+                error.clone().map_or(JsonValue::Null, JsonValue::String),
+            );
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl FromStr for Record {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = JsonValue::from_str(s).or(Err("not valid JSON."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected record to be a JSON object.")?;
+
+        let string = |key: &str| match json.get(key) {
+            None | Some(JsonValue::Null) => Ok(None),
+            Some(v) => v
+                .get::<String>()
+                .cloned()
+                .map(Some)
+                .ok_or(format!("Expected record.{key} to be a string.")),
+        };
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected record.{key} to be a number."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let measurement = Measurement {
+            nanos: number("nanos")?,
+            samples: number("samples")? as u128,
+            stats: json
+                .get("stats")
+                .filter(|v| !v.is_null())
+                .map(Stats::try_from)
+                .transpose()?,
+            memory: json
+                .get("memory")
+                .filter(|v| !v.is_null())
+                .map(MemoryUsage::try_from)
+                .transpose()?,
+        };
+
+        match json.get("kind").and_then(|v| v.get::<String>()) {
+            Some(kind) if kind == "parse" => Ok(Record::Parse(measurement)),
+            Some(kind) if kind == "part" => {
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let part = match number("part")? as u8 {
+                    part @ (1 | 2) => part,
+                    _ => return Err("Expected record.part to be 1 or 2.".into()),
+                };
+
+                Ok(Record::Part {
+                    part,
+                    answer: string("answer")?,
+                    error: string("error")?,
+                    measurement,
+                })
+            }
+            _ => Err("Expected record.kind to be `parse` or `part`.".into()),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Measurement, Record, collect, emit, parse_records};
+    use crate::template::memory::MemoryUsage;
+    use crate::template::timings::Stats;
+
+    #[test]
+    fn roundtrips_records() {
+        let records = [
+            Record::Parse(Measurement {
+                nanos: 1500_f64,
+                samples: 1,
+                stats: None,
+                memory: None,
+            }),
+            Record::Part {
+                part: 2,
+                answer: Some("#..#\n.##. (1s)".into()),
+                error: None,
+                measurement: Measurement {
+                    nanos: 2000_f64,
+                    samples: 100,
+                    stats: Some(Stats {
+                        mean: 2000_f64,
+                        min: 1000_f64,
+                        median: 1500_f64,
+                        p95: 3000_f64,
+                        max: 10000_f64,
+                        stddev: 500_f64,
+                    }),
+                    memory: Some(MemoryUsage {
+                        peak_bytes: 4096,
+                        allocations: 12,
+                    }),
+                },
+            },
+            Record::Part {
+                part: 1,
+                answer: None,
+                error: None,
+                measurement: Measurement {
+                    nanos: 19_f64,
+                    samples: 1,
+                    stats: None,
+                    memory: None,
+                },
+            },
+            Record::Part {
+                part: 2,
+                answer: None,
+                error: Some("expected a number, found `x`".into()),
+                measurement: Measurement {
+                    nanos: 21_f64,
+                    samples: 1,
+                    stats: None,
+                    memory: None,
+                },
+            },
+        ];
+
+        let content: String = records
+            .iter()
+            .map(|r| tinyjson::JsonValue::from(r).stringify().unwrap() + "\n")
+            .collect();
+
+        assert_eq!(parse_records(&content), records);
+    }
+
+    #[test]
+    fn skips_malformed_records() {
+        let content = concat!(
+            "{ \"kind\": \"part\", \"part\": 3, \"nanos\": 1, \"samples\": 1 }\n",
+            "Part 1: 42\n",
+            "\n",
+            "{ \"kind\": \"part\", \"part\": 1, \"answer\": \"42\", \"nanos\": 1, \"samples\": 1 }\n",
+        );
+
+        let records = parse_records(content);
+        assert_eq!(records.len(), 1);
+        assert!(matches!(&records[0], Record::Part { answer: Some(a), .. } if a == "42"));
+    }
+
+    #[test]
+    fn collects_records_in_process() {
+        let record = Record::Part {
+            part: 1,
+            answer: Some("42".into()),
+            error: None,
+            measurement: Measurement {
+                nanos: 1_f64,
+                samples: 1,
+                stats: None,
+                memory: None,
+            },
+        };
+
+        let (result, records) = collect(|| {
+            emit(&record);
+            "done"
+        });
+
+        assert_eq!(result, "done");
+        assert_eq!(records, vec![record]);
+        // records emitted afterwards are not collected anymore.
+        assert_eq!(collect(|| ()).1, vec![]);
+    }
+}
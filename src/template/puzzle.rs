@@ -0,0 +1,381 @@
+/// Module that finds the examples in a puzzle description downloaded as markdown.
+use std::mem;
+
+/// A fenced code block of a puzzle description.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CodeBlock {
+    /// Contents of the block, ending with a newline like an input file.
+    pub text: String,
+    /// Text between the previous code block (or the start) and this one.
+    pub preceding: String,
+}
+
+impl CodeBlock {
+    /// Returns `true` if the block follows a paragraph that introduces an example.
+    pub fn is_example(&self) -> bool {
+        self.preceding.to_lowercase().contains("for example")
+    }
+}
+
+/// The fenced code blocks of a puzzle description, in order.
+pub fn code_blocks(markdown: &str) -> Vec<CodeBlock> {
+    let mut blocks = vec![];
+    let mut preceding = String::new();
+    let mut block: Option<String> = None;
+
+    for line in markdown.lines() {
+        match (&mut block, line.trim_end() == "```") {
+            (None, true) => block = Some(String::new()),
+            (None, false) => {
+                preceding.push_str(line);
+                preceding.push('\n');
+            }
+            (Some(text), true) => {
+                blocks.push(CodeBlock {
+                    text: mem::take(text),
+                    preceding: mem::take(&mut preceding),
+                });
+                block = None;
+            }
+            (Some(text), false) => {
+                text.push_str(line);
+                text.push('\n');
+            }
+        }
+    }
+
+    blocks
+}
+
+/// Indices of the blocks that directly follow a "For example" paragraph, without repeated examples.
+/// Usually the first one is the example of part one and a second one introduces part two.
+pub fn default_examples(blocks: &[CodeBlock]) -> Vec<usize> {
+    let mut examples: Vec<usize> = vec![];
+
+    for (i, block) in blocks.iter().enumerate() {
+        if block.is_example()
+            && !block.text.trim().is_empty()
+            && examples.iter().all(|&j| blocks[j].text != block.text)
+        {
+            examples.push(i);
+        }
+    }
+
+    examples
+}
+
+/// Heading that starts the description of part two.
+const PART_TWO_HEADING: &str = "## --- Part Two ---";
+
+/// The answers to the examples of both parts, i.e. the last highlighted value of each part's description.
+/// The answer to part two is only known once part two is unlocked and the puzzle is downloaded again.
+pub fn expected_answers(markdown: &str) -> [Option<String>; 2] {
+    let (part_one, part_two) = match markdown.find(PART_TWO_HEADING) {
+        Some(i) => (&markdown[..i], Some(&markdown[i..])),
+        None => (markdown, None),
+    };
+
+    [final_answer(part_one), part_two.and_then(final_answer)]
+}
+
+/// Returns `true` if the example is a code block of part two's description. Puzzles often have
+/// several examples in part one, so a second example file is not necessarily the one of part two.
+pub fn is_part_two_example(markdown: &str, example: &str) -> bool {
+    markdown.find(PART_TWO_HEADING).is_some_and(|i| {
+        code_blocks(&markdown[i..])
+            .iter()
+            .any(|block| block.text == example)
+    })
+}
+
+/// The last highlighted value of a part's description.
+fn final_answer(part: &str) -> Option<String> {
+    // the paragraph with the submitted answer is not part of the description.
+    let description = part.split("Your puzzle answer was").next()?;
+    highlighted(description).pop()
+}
+
+/// Inline code that is emphasized as well, e.g. `` `*42*` `` or `` *`42`* ``, outside of code blocks.
+fn highlighted(markdown: &str) -> Vec<String> {
+    let mut values = vec![];
+    let mut in_block = false;
+
+    for line in markdown.lines() {
+        if line.trim_end() == "```" {
+            in_block = !in_block;
+            continue;
+        }
+
+        if in_block {
+            continue;
+        }
+
+        // inline code spans are the odd segments between backticks.
+        let segments: Vec<&str> = line.split('`').collect();
+
+        for i in (1..segments.len().saturating_sub(1)).step_by(2) {
+            let code = segments[i];
+
+            let value =
+                if let Some(value) = code.strip_prefix('*').and_then(|c| c.strip_suffix('*')) {
+                    value
+                } else if segments[i - 1].ends_with('*') && segments[i + 1].starts_with('*') {
+                    code
+                } else {
+                    continue;
+                };
+
+            if !value.is_empty() {
+                values.push(value.to_string());
+            }
+        }
+    }
+
+    values
+}
+
+/// Fill the expected answers into the `test_part_one` and `test_part_two` functions of a solution module.
+/// Only assertions that still expect `None` are changed, every other line is kept as is.
+/// With `part_two_example`, part two's test reads the `NN-2.txt` example instead of `NN.txt`,
+/// like `cargo solve --example` does.
+///
+/// Returns the updated source and the parts whose test was updated.
+pub fn fill_expected_answers(
+    source: &str,
+    answers: &[Option<String>; 2],
+    part_two_example: bool,
+) -> (String, Vec<u8>) {
+    let mut source = source.to_string();
+    let mut updated = vec![];
+
+    for (part, name, answer) in [
+        (1, "test_part_one", &answers[0]),
+        (2, "test_part_two", &answers[1]),
+    ] {
+        let Some(start) = source.find(&format!("fn {name}()")) else {
+            continue;
+        };
+
+        // the body ends at the closing brace of the test function.
+        let end = source[start..]
+            .find("\n    }")
+            .map_or(source.len(), |i| start + i);
+
+        let body = &source[start..end];
+        let mut new_body = body.to_string();
+
+        if let Some(answer) = answer {
+            new_body = new_body.replace(
+                "assert_eq!(result, None);",
+                &format!("assert_eq!(result, Some({}));", answer_literal(answer)),
+            );
+        }
+
+        // the example of part two may be extracted after its answer was filled in.
+        if part == 2 && part_two_example {
+            new_body = read_part_two_example(&new_body);
+        }
+
+        if new_body != body {
+            source.replace_range(start..end, &new_body);
+            updated.push(part);
+        }
+    }
+
+    (source, updated)
+}
+
+/// Switch `read_file("examples", YEAR, DAY)` to `read_file_part("examples", YEAR, DAY, 2)`.
+/// The call is matched regardless of whitespace, since rustfmt may split it across lines.
+fn read_part_two_example(body: &str) -> String {
+    let mut body = body.to_string();
+    let mut from = 0;
+
+    while let Some(i) = body[from..].find("read_file(") {
+        let args_start = from + i + "read_file(".len();
+        let Some(args_len) = body[args_start..].find(')') else {
+            break;
+        };
+        let args = &body[args_start..args_start + args_len];
+
+        let compact: String = args.chars().filter(|c| !c.is_whitespace()).collect();
+        if compact.trim_end_matches(',') == "\"examples\",YEAR,DAY" {
+            // keep the formatting of the call, including a trailing comma.
+            let day_end = args_start + args.rfind("DAY").unwrap() + "DAY".len();
+            body.insert_str(day_end, ", 2");
+            body.replace_range(from + i..from + i + "read_file".len(), "read_file_part");
+        }
+
+        from = args_start;
+    }
+
+    body
+}
+
+/// Numbers are compared as integers, anything else as a `String`.
+fn answer_literal(answer: &str) -> String {
+    if answer.parse::<i128>().is_ok() {
+        answer.to_string()
+    } else {
+        format!("String::from({answer:?})")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        code_blocks, default_examples, expected_answers, fill_expected_answers, is_part_two_example,
+    };
+
+    const PUZZLE: &str = "## --- Day 1: Test ---
+
+The dial starts at `50`:
+
+```
+50
+```
+
+For example, suppose the attached document contained the following rotations:
+
+```
+L68
+L30
+```
+
+Following these rotations would cause the dial to move as follows:
+
+```
+- The dial starts by pointing at 50.
+```
+
+## --- Part Two ---
+
+For example, the same rotations:
+
+```
+L68
+L30
+```
+
+Now, for example, suppose there are more:
+
+```
+R48
+```
+";
+
+    #[test]
+    fn finds_code_blocks() {
+        let blocks = code_blocks(PUZZLE);
+        assert_eq!(blocks.len(), 5);
+        assert_eq!(blocks[1].text, "L68\nL30\n");
+        assert!(!blocks[0].is_example());
+        assert!(blocks[1].is_example());
+        assert!(!blocks[2].is_example());
+    }
+
+    #[test]
+    fn picks_examples_after_for_example() {
+        let blocks = code_blocks(PUZZLE);
+        assert_eq!(default_examples(&blocks), vec![1, 4]);
+    }
+
+    #[test]
+    fn handles_puzzles_without_examples() {
+        let blocks = code_blocks("## --- Day 1 ---\n\nNo code here.\n");
+        assert!(blocks.is_empty());
+        assert!(default_examples(&blocks).is_empty());
+    }
+
+    #[test]
+    fn finds_part_two_examples() {
+        assert!(is_part_two_example(PUZZLE, "R48\n"));
+        assert!(!is_part_two_example(
+            PUZZLE,
+            "- The dial starts by pointing at 50.\n"
+        ));
+        assert!(!is_part_two_example(
+            "For example:\n\n```\nR48\n```\n",
+            "R48\n"
+        ));
+    }
+
+    #[test]
+    fn finds_expected_answers() {
+        let markdown = "## --- Day 1: Test ---
+
+For example:
+
+```
+L68
+*L30*
+```
+
+The dial points at `0` a total of `*3*` times. Counting *`12`* times, the password is `*3*`.
+
+## --- Part Two ---
+
+Following the same rotations, the dial points at zero *`6`* times.
+
+Your puzzle answer was `1145`.
+";
+
+        assert_eq!(
+            expected_answers(markdown),
+            [Some("3".into()), Some("6".into())]
+        );
+        assert_eq!(
+            expected_answers("## --- Day 1 ---\n\nThe code is `*abc*`.\n"),
+            [Some("abc".into()), None]
+        );
+    }
+
+    #[test]
+    fn fills_expected_answers() {
+        let source = r#"advent_of_code::solution!(2025, 1);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_edge_case() {
+        assert_eq!(part_one("L1\n"), None);
+    }
+}
+"#;
+
+        let (filled, updated) =
+            fill_expected_answers(source, &[Some("3".into()), Some("abc".into())], true);
+        assert_eq!(updated, vec![1, 2]);
+        assert!(filled.contains("assert_eq!(result, Some(3));"));
+        assert!(filled.contains("assert_eq!(result, Some(String::from(\"abc\")));"));
+        assert!(filled.contains("read_file_part(\"examples\", YEAR, DAY, 2)"));
+        assert!(filled.contains("assert_eq!(part_one(\"L1\\n\"), None);"));
+
+        // answers that are already filled in are kept.
+        let (refilled, updated) = fill_expected_answers(&filled, &[Some("4".into()), None], false);
+        assert!(updated.is_empty());
+        assert_eq!(refilled, filled);
+
+        // an example for part two that shows up later is still picked up.
+        let (filled, _) = fill_expected_answers(source, &[None, Some("6".into())], false);
+        let (refilled, updated) = fill_expected_answers(&filled, &[None, Some("6".into())], true);
+        assert_eq!(updated, vec![2]);
+        assert!(refilled.contains("read_file_part(\"examples\", YEAR, DAY, 2)"));
+    }
+}
@@ -1,9 +1,11 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::{fs, io, path::Path};
 
-use crate::template::Day;
-use crate::template::timings::Timings;
+use crate::template::chart::{self, CHART_PATH};
+use crate::template::memory::{MemoryUsage, format_bytes};
+use crate::template::timings::{Statistic, Timings};
+use crate::template::{Year, get_path_for_bin};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -21,22 +23,18 @@ impl From<std::io::Error> for Error {
 }
 
 pub struct TablePosition {
-    pos_start: usize,
-    pos_end: usize,
+    pub pos_start: usize,
+    pub pos_end: usize,
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+/// Find the table enclosed by a pair of markers, including the markers.
+pub fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
-        return Err(Error::Parser(
-            "{}: too many occurences of marker in README.".into(),
-        ));
+        return Err(Error::Parser(format!(
+            "{marker}: too many occurences of marker in README."
+        )));
     }
 
     let pos_start = matches
@@ -52,47 +50,132 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
+fn construct_table(
+    prefix: &str,
+    year: Year,
+    timings: Timings,
+    total_millis: f64,
+    statistic: Statistic,
+    chart: Option<&str>,
+) -> String {
     let header = format!("{prefix} Benchmarks");
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+    // the parse column is only shown once a solution uses a shared parser.
+    let has_parse = timings.data.iter().any(|t| t.parse.is_some());
+
+    let suffix = match statistic {
+        Statistic::Mean => "",
+        Statistic::Median => " (median)",
+    };
+
+    // memory columns are only shown once a day was timed with `--memory`.
+    let has_memory = timings
+        .data
+        .iter()
+        .any(|t| t.memory.iter().any(Option::is_some));
+
+    let (mut columns, mut alignment) = if has_parse {
+        (
+            format!("| Day | Parse | Part 1{suffix} | Part 2{suffix} |"),
+            "| :---: | :---: | :---: | :---:  |".to_string(),
+        )
+    } else {
+        (
+            format!("| Day | Part 1{suffix} | Part 2{suffix} |"),
+            "| :---: | :---: | :---:  |".to_string(),
+        )
+    };
+
+    if has_memory {
+        columns.push_str(" Part 1 memory | Part 2 memory |");
+        alignment.push_str(" :---: | :---: |");
+    }
+
+    let mut lines: Vec<String> = vec![MARKER.into(), header, String::new(), columns, alignment];
 
     for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
+        let path = get_path_for_bin(year, timing.day);
+        let parse = if has_parse {
+            format!(" `{}` |", timing.parse.as_deref().unwrap_or("-"))
+        } else {
+            String::new()
+        };
+
+        let memory = if has_memory {
+            format!(
+                " {} | {} |",
+                format_memory(timing.memory[0]),
+                format_memory(timing.memory[1])
+            )
+        } else {
+            String::new()
+        };
+
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+            "| [Day {}]({}) |{} `{}` | `{}` |{}",
             timing.day.into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            parse,
+            timing.part_time(1, statistic).unwrap_or_else(|| "-".into()),
+            timing.part_time(2, statistic).unwrap_or_else(|| "-".into()),
+            memory
         ));
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
+
+    if let Some(chart) = chart {
+        lines.push(String::new());
+        lines.push(format!("![Benchmarks](./{chart})"));
+    }
+
     lines.push(MARKER.into());
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+fn format_memory(memory: Option<MemoryUsage>) -> String {
+    memory.map_or_else(
+        || "`-`".into(),
+        |m| format!("`{}`, {} allocs", format_bytes(m.peak_bytes), m.allocations),
+    )
+}
+
+fn update_content(
+    s: &mut String,
+    year: Year,
+    timings: Timings,
+    total_millis: f64,
+    statistic: Statistic,
+    chart: Option<&str>,
+) -> Result<(), Error> {
+    let positions = locate_table(s, MARKER)?;
+    let table = construct_table("##", year, timings, total_millis, statistic, chart);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
+/// Update the benchmarks table in the readme. With `with_chart`, also renders the timings to
+/// [`CHART_PATH`] and shows it below the table.
+pub fn update(
+    year: Year,
+    timings: Timings,
+    statistic: Statistic,
+    with_chart: bool,
+) -> Result<(), Error> {
+    if with_chart {
+        if let Some(dir) = Path::new(CHART_PATH).parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(CHART_PATH, chart::render(year, &timings, statistic))?;
+    }
+
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    let total_millis = timings.total_millis_by(statistic);
+    let chart = with_chart.then_some(CHART_PATH);
+    update_content(&mut readme, year, timings, total_millis, statistic, chart)?;
     fs::write(path, &readme)?;
     Ok(())
 }
@@ -100,28 +183,45 @@ pub fn update(timings: Timings) -> Result<(), Error> {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{MARKER, update_content};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use crate::template::memory::MemoryUsage;
+    use crate::template::timings::{Statistic, Stats, Timing, Timings};
+    use crate::{day, year};
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
+                    parse: None,
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    stats: [None, None],
+                    memory: [None, None],
+                    errors: [None, None],
+                    policy: None,
                 },
                 Timing {
                     day: day!(2),
+                    parse: None,
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
+                    stats: [None, None],
+                    memory: [None, None],
+                    errors: [None, None],
+                    policy: None,
                 },
                 Timing {
                     day: day!(4),
+                    parse: None,
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
                     total_nanos: 9e+10,
+                    stats: [None, None],
+                    memory: [None, None],
+                    errors: [None, None],
+                    policy: None,
                 },
             ],
         }
@@ -131,28 +231,68 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            year!(2025),
+            get_mock_timings(),
+            190.0,
+            Statistic::Mean,
+            None,
+        )
+        .unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            year!(2025),
+            get_mock_timings(),
+            190.0,
+            Statistic::Mean,
+            None,
+        )
+        .unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            year!(2025),
+            get_mock_timings(),
+            190.0,
+            Statistic::Mean,
+            None,
+        )
+        .unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            year!(2025),
+            get_mock_timings(),
+            190.0,
+            Statistic::Mean,
+            None,
+        )
+        .unwrap();
+        update_content(
+            &mut s,
+            year!(2025),
+            get_mock_timings(),
+            190.0,
+            Statistic::Mean,
+            None,
+        )
+        .unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -160,7 +300,15 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            year!(2025),
+            get_mock_timings(),
+            190.0,
+            Statistic::Mean,
+            None,
+        )
+        .unwrap();
         let expected = [
             "foo",
             "bar",
@@ -169,9 +317,9 @@ mod tests {
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/2025_01.rs) | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2025_02.rs) | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2025_04.rs) | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -180,4 +328,66 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+    #[test]
+    fn embeds_chart() {
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(
+            &mut s,
+            year!(2025),
+            get_mock_timings(),
+            190.0,
+            Statistic::Mean,
+            Some(".assets/benchmarks.svg"),
+        )
+        .unwrap();
+        assert!(s.ends_with(
+            "**Total: 190.00ms**\n\n![Benchmarks](./.assets/benchmarks.svg)\n<!--- benchmarking table --->"
+        ));
+    }
+    #[test]
+    fn format_median_benchmarks() {
+        let mut timings = get_mock_timings();
+        timings.data[0].stats[0] = Some(Stats {
+            mean: 10_000_000_f64,
+            min: 1_000_000_f64,
+            median: 2_000_000_f64,
+            p95: 30_000_000_f64,
+            max: 40_000_000_f64,
+            stddev: 1_000_000_f64,
+        });
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, year!(2025), timings, 190.0, Statistic::Median, None).unwrap();
+        assert!(s.contains("| Day | Part 1 (median) | Part 2 (median) |"));
+        // parts without stats fall back to the mean.
+        assert!(s.contains("| [Day 1](./src/bin/2025_01.rs) | `2.0ms` | `20ms` |"));
+    }
+    #[test]
+    fn format_parse_benchmarks() {
+        let mut timings = get_mock_timings();
+        timings.data[1].parse = Some("5ms".into());
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, year!(2025), timings, 190.0, Statistic::Mean, None).unwrap();
+        assert!(s.contains("| Day | Parse | Part 1 | Part 2 |"));
+        assert!(s.contains("| [Day 1](./src/bin/2025_01.rs) | `-` | `10ms` | `20ms` |"));
+        assert!(s.contains("| [Day 2](./src/bin/2025_02.rs) | `5ms` | `30ms` | `40ms` |"));
+    }
+    #[test]
+    fn format_memory_benchmarks() {
+        let mut timings = get_mock_timings();
+        timings.data[0].memory[0] = Some(MemoryUsage {
+            peak_bytes: 1536,
+            allocations: 12,
+        });
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, year!(2025), timings, 190.0, Statistic::Mean, None).unwrap();
+        assert!(s.contains("| Day | Part 1 | Part 2 | Part 1 memory | Part 2 memory |"));
+        assert!(s.contains("| :---: | :---: | :---:  | :---: | :---: |"));
+        assert!(s.contains(
+            "| [Day 1](./src/bin/2025_01.rs) | `10ms` | `20ms` | `1.5 KiB`, 12 allocs | `-` |"
+        ));
+        assert!(s.contains("| [Day 2](./src/bin/2025_02.rs) | `30ms` | `40ms` | `-` | `-` |"));
+    }
 }
@@ -0,0 +1,222 @@
+/// Module that fills the `advent_readme_stars` table of the readme from local data, so the table
+/// can be kept up to date without the Github action.
+use std::fs;
+
+use crate::template::answers::Answers;
+use crate::template::aoc_client::puzzle_url;
+use crate::template::readme_benchmarks::{Error, locate_table};
+use crate::template::submissions::Submissions;
+use crate::template::{Day, Year};
+
+static MARKER: &str = "<!--- advent_readme_stars table --->";
+static STAR: &str = "⭐";
+
+/// The parts of a day that have a star.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stars {
+    pub day: Day,
+    pub parts: [bool; 2],
+}
+
+/// Collect the solved parts of a year. A part counts as solved if it has a correct submission or a
+/// known answer in `data/answers.json`. A stored timing does not tell if the part returned the right
+/// answer, so timings do not count. Days without a star are left out.
+pub fn collect(year: Year, submissions: &Submissions, answers: &Answers) -> Vec<Stars> {
+    let is_solved = |day: Day, part: u8| {
+        submissions.correct_answer(year, day, part).is_some()
+            || answers.get(year, day, part).is_some()
+    };
+
+    let mut stars: Vec<Stars> = year
+        .days()
+        .map(|day| Stars {
+            day,
+            parts: [is_solved(day, 1), is_solved(day, 2)],
+        })
+        .collect();
+
+    // the last day has a single puzzle, its second star is awarded for collecting all others.
+    if let Some((last, others)) = stars.split_last_mut()
+        && last.parts[0]
+        && others.iter().all(|s| s.parts == [true, true])
+    {
+        last.parts[1] = true;
+    }
+
+    stars.retain(|s| s.parts.contains(&true));
+    stars
+}
+
+fn construct_table(year: Year, stars: &[Stars]) -> String {
+    let mut lines: Vec<String> = vec![
+        MARKER.into(),
+        format!("## {year} Results"),
+        String::new(),
+        "| Day | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---: |".into(),
+    ];
+
+    for s in stars {
+        let [part_1, part_2] = s.parts.map(|solved| if solved { STAR } else { "" });
+
+        lines.push(format!(
+            "| [Day {}]({}) | {part_1} | {part_2} |",
+            s.day.into_inner(),
+            puzzle_url(year, s.day)
+        ));
+    }
+
+    lines.push(MARKER.into());
+
+    lines.join("\n")
+}
+
+fn update_content(s: &mut String, year: Year, stars: &[Stars]) -> Result<(), Error> {
+    let positions = locate_table(s, MARKER)?;
+    let table = construct_table(year, stars);
+    s.replace_range(positions.pos_start..positions.pos_end, &table);
+    Ok(())
+}
+
+/// Update the stars table in the readme and return the number of stars.
+pub fn update(year: Year) -> Result<usize, Error> {
+    let stars = collect(
+        year,
+        &Submissions::read_from_file().map_err(Error::Parser)?,
+        &Answers::read_from_file().map_err(Error::Parser)?,
+    );
+
+    let path = "README.md";
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    update_content(&mut readme, year, &stars)?;
+    fs::write(path, &readme)?;
+
+    Ok(stars
+        .iter()
+        .flat_map(|s| s.parts)
+        .filter(|&solved| solved)
+        .count())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{MARKER, Stars, collect, update_content};
+    use crate::template::Day;
+    use crate::template::answers::Answers;
+    use crate::template::submissions::{Submission, Submissions, Verdict};
+    use crate::{day, year};
+
+    #[test]
+    fn collects_stars_from_local_data() {
+        let mut answers = Answers::default();
+        answers.set(year!(2025), day!(1), 1, "1");
+        answers.set(year!(2025), day!(1), 2, "2");
+        answers.set(year!(2025), day!(2), 1, "3");
+        answers.set(year!(2025), day!(3), 1, "42");
+
+        let submissions = Submissions {
+            data: vec![
+                Submission::now(year!(2025), day!(5), 2, "7", Verdict::Correct),
+                Submission::now(year!(2025), day!(6), 1, "8", Verdict::TooHigh),
+            ],
+        };
+
+        let stars = collect(year!(2025), &submissions, &answers);
+        assert_eq!(
+            stars,
+            vec![
+                Stars {
+                    day: day!(1),
+                    parts: [true, true]
+                },
+                Stars {
+                    day: day!(2),
+                    parts: [true, false]
+                },
+                Stars {
+                    day: day!(3),
+                    parts: [true, false]
+                },
+                Stars {
+                    day: day!(5),
+                    parts: [false, true]
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn awards_last_star_for_all_others() {
+        let mut answers = Answers::default();
+        for day in 1..=12 {
+            let day = Day::new(day).unwrap();
+            answers.set(year!(2025), day, 1, "1");
+            if day < day!(12) {
+                answers.set(year!(2025), day, 2, "2");
+            }
+        }
+
+        let stars = collect(year!(2025), &Submissions::default(), &answers);
+        assert_eq!(stars.len(), 12);
+        assert_eq!(stars[11].parts, [true, true]);
+
+        answers.data.retain(|answer| answer.day != day!(1));
+
+        let stars = collect(year!(2025), &Submissions::default(), &answers);
+        assert_eq!(stars[10].parts, [true, false]);
+    }
+
+    #[test]
+    fn ignores_unsolved_parts() {
+        // part two of the template returns `None`, its timing is stored but never submitted.
+        let submissions = Submissions {
+            data: vec![
+                Submission::now(year!(2025), day!(1), 1, "6", Verdict::Correct),
+                Submission::now(year!(2025), day!(1), 2, "0", Verdict::Incorrect),
+            ],
+        };
+
+        let stars = collect(year!(2025), &submissions, &Answers::default());
+        assert_eq!(
+            stars,
+            vec![Stars {
+                day: day!(1),
+                parts: [true, false]
+            }]
+        );
+    }
+
+    #[test]
+    fn updates_stars_table() {
+        let mut s = format!("foo\n{MARKER}\nold\n{MARKER}\nbar");
+        let stars = [
+            Stars {
+                day: day!(1),
+                parts: [true, true],
+            },
+            Stars {
+                day: day!(2),
+                parts: [true, false],
+            },
+        ];
+
+        update_content(&mut s, year!(2025), &stars).unwrap();
+
+        let expected = [
+            "foo",
+            "<!--- advent_readme_stars table --->",
+            "## 2025 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](https://adventofcode.com/2025/day/1) | ⭐ | ⭐ |",
+            "| [Day 2](https://adventofcode.com/2025/day/2) | ⭐ |  |",
+            "<!--- advent_readme_stars table --->",
+            "bar",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+}
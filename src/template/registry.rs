@@ -0,0 +1,160 @@
+/// Module for the solutions that are compiled into the main binary.
+///
+/// With the `registry` feature, the build script includes every `src/bin/<year>_<day>.rs` as a
+/// module of the main binary, which registers them on startup. `all`, `time` and `verify` build
+/// the main binary with that feature and continue in it, see [`switch_to_main_binary`], so they
+/// call the solutions in-process instead of spawning `cargo run --bin <day>`.
+use std::{
+    env, fs,
+    io::{self, Write},
+    process::{self, Command},
+    sync::OnceLock,
+};
+
+use crate::template::run_multi::{RunOptions, child_commands};
+use crate::template::{Day, Year, get_bin_name, runner};
+
+static DAYS: OnceLock<&'static [RegisteredDay]> = OnceLock::new();
+
+/// Argument that starts the main binary as the solution named by the next one, e.g.
+/// `advent_of_code --run-solution 2025_01 --time`. Used when a solution needs a process of its own.
+pub const RUN_ARG: &str = "--run-solution";
+
+/// A solution compiled into the main binary.
+#[derive(Debug)]
+pub struct RegisteredDay {
+    pub year: Year,
+    pub day: Day,
+    /// Runs the solution like its binary would, reading the arguments from [`runner::with_args`].
+    pub run: fn(),
+}
+
+/// Make the solutions compiled into the main binary available to [`find`]. Only the first call has
+/// an effect. If the binary was started with [`RUN_ARG`], runs that solution and exits.
+pub fn register(days: &'static [RegisteredDay]) {
+    let _ = DAYS.set(days);
+
+    let mut args = env::args().skip(1);
+    if args.next().as_deref() != Some(RUN_ARG) {
+        return;
+    }
+
+    let args: Vec<String> = args.collect();
+    let Some(registered) = args.first().and_then(|bin_name| {
+        days.iter()
+            .find(|registered| get_bin_name(registered.year, registered.day) == *bin_name)
+    }) else {
+        eprintln!("Unexpected command-line input. Format: {RUN_ARG} <year>_<day> [args]");
+        process::exit(1);
+    };
+
+    runner::with_args(args, registered.run);
+
+    // `process::exit` skips flushing stdout.
+    let _ = io::stdout().flush();
+    process::exit(0);
+}
+
+/// The registered solution of a day, if it was compiled into the running binary.
+pub fn find(year: Year, day: Day) -> Option<&'static RegisteredDay> {
+    DAYS.get()?
+        .iter()
+        .find(|registered| registered.year == year && registered.day == day)
+}
+
+/// Run the current command again in the main binary built with the `registry` feature and exit
+/// with its status. Returns right away if the running binary has the registry already, and after
+/// reporting why if the main binary cannot be built with it, e.g. because a day does not compile.
+/// The command then goes on in the running binary and runs every day with `cargo run`.
+pub fn switch_to_main_binary(options: &RunOptions) {
+    if DAYS.get().is_some() {
+        return;
+    }
+
+    let features = bin_features().unwrap_or_else(|e| {
+        eprintln!("Failed to read the solutions in `src/bin`: {e}");
+        vec![]
+    });
+
+    let executable = match child_commands::build_main_binary(options, &features) {
+        Ok(Ok(executable)) => executable,
+        Ok(Err(output)) => {
+            output.stderr.iter().for_each(|line| eprintln!("{line}"));
+            eprintln!(
+                "Could not build the solutions into the main binary, running each day with `cargo run` instead."
+            );
+            return;
+        }
+        Err(e) => {
+            eprintln!(
+                "Could not build the solutions into the main binary: {e:?}. Running each day with `cargo run` instead."
+            );
+            return;
+        }
+    };
+
+    match Command::new(&executable)
+        .args(env::args_os().skip(1))
+        .status()
+    {
+        Ok(status) => process::exit(status.code().unwrap_or(1)),
+        Err(e) => eprintln!(
+            "Could not start `{}`: {e}. Running each day with `cargo run` instead.",
+            executable.display()
+        ),
+    }
+}
+
+/// Features the solutions in `src/bin` enable with `#![feature(...)]`. Crate-level attributes only
+/// apply to the root of a crate, so the main binary has to enable them when it includes the bins.
+fn bin_features() -> io::Result<Vec<String>> {
+    let mut features = vec![];
+
+    for entry in fs::read_dir("src/bin")? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|extension| extension == "rs") {
+            features.extend(parse_features(&fs::read_to_string(path)?));
+        }
+    }
+
+    features.sort_unstable();
+    features.dedup();
+    Ok(features)
+}
+
+/// Names of the features enabled with `#![feature(...)]`.
+fn parse_features(source: &str) -> Vec<String> {
+    source
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("#![feature(")?.strip_suffix(")]"))
+        .flat_map(|names| names.split(','))
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::parse_features;
+
+    #[test]
+    fn parses_features() {
+        let source = concat!(
+            "#![feature(ascii_char)]\n",
+            "#![feature(ascii_char_variants, exact_length_collection)]\n",
+            "// #![feature(commented_out)]\n",
+            "advent_of_code::solution!(2025, 4);\n",
+        );
+
+        assert_eq!(
+            parse_features(source),
+            vec![
+                "ascii_char",
+                "ascii_char_variants",
+                "exact_length_collection"
+            ]
+        );
+    }
+}
@@ -0,0 +1,411 @@
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, Year};
+
+use super::timings::{Timing, Timings};
+
+/// Machine-readable output formats for `all`, `solve` and `time`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Junit,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Self::Json),
+            "junit" => Ok(Self::Junit),
+            _ => Err(format!("unknown format `{s}`, expecting `json` or `junit`")),
+        }
+    }
+}
+
+/// Outcome of running one part of a solution.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Solved,
+    /// The part returned `None` or is not implemented.
+    Unsolved,
+    /// The part returned an error.
+    Failed,
+    /// The solution bin exited abnormally before printing the part.
+    Panicked,
+    /// The solution bin was killed for running longer than `--timeout`.
+    TimedOut,
+    /// The solution bin ran out of memory under `--max-memory`.
+    OutOfMemory,
+}
+
+impl Status {
+    /// The solution bin did not finish, so the part has neither an answer nor an error of its own.
+    pub fn is_error(self) -> bool {
+        matches!(
+            self,
+            Status::Panicked | Status::TimedOut | Status::OutOfMemory
+        )
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Status::Solved => "solved",
+            Status::Unsolved => "unsolved",
+            Status::Failed => "failed",
+            Status::Panicked => "panicked",
+            Status::TimedOut => "timed out",
+            Status::OutOfMemory => "out of memory",
+        })
+    }
+}
+
+/// Result of one part of a solution.
+#[derive(Clone, Debug)]
+pub struct PartReport {
+    pub answer: Option<String>,
+    pub status: Status,
+    /// Error message of a part that failed.
+    pub error: Option<String>,
+    pub nanos: Option<f64>,
+    pub samples: Option<u128>,
+}
+
+/// Results of a single day. `timing` holds the benchmark times, if the day was benched.
+#[derive(Clone, Debug)]
+pub struct DayReport {
+    pub timing: Timing,
+    pub parts: [PartReport; 2],
+}
+
+impl DayReport {
+    pub fn day(&self) -> Day {
+        self.timing.day
+    }
+
+    /// Sum of the part durations in nanoseconds.
+    pub fn total_nanos(&self) -> f64 {
+        // `sum` of no floats is `-0.0`, which would be rendered with its sign.
+        self.parts
+            .iter()
+            .filter_map(|p| p.nanos)
+            .fold(0_f64, |acc, n| acc + n)
+    }
+}
+
+/// Results of a set of days.
+#[derive(Clone, Debug)]
+pub struct Report {
+    pub year: Year,
+    pub days: Vec<DayReport>,
+}
+
+impl Report {
+    /// Benchmark times of all days in the report that have at least one part solved.
+    pub fn timings(&self) -> Timings {
+        Timings {
+            data: self
+                .days
+                .iter()
+                .filter(|d| d.parts.iter().any(|p| p.status == Status::Solved))
+                .map(|d| d.timing.clone())
+                .collect(),
+        }
+    }
+
+    /// Render the report in a machine-readable format.
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Json => JsonValue::from(self).stringify().unwrap(),
+            Format::Junit => self.to_junit(),
+        }
+    }
+
+    fn to_junit(&self) -> String {
+        let seconds = |nanos: f64| nanos / 1_000_000_000_f64;
+
+        let count = |is_status: fn(Status) -> bool| {
+            self.days
+                .iter()
+                .flat_map(|d| &d.parts)
+                .filter(|p| is_status(p.status))
+                .count()
+        };
+
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+
+        xml.push_str(&format!(
+            "<testsuites name=\"advent_of_code {}\" tests=\"{}\" skipped=\"{}\" failures=\"{}\" errors=\"{}\" time=\"{:.6}\">\n",
+            self.year,
+            self.days.len() * 2,
+            count(|s| s == Status::Unsolved),
+            count(|s| s == Status::Failed),
+            count(Status::is_error),
+            seconds(
+                self.days
+                    .iter()
+                    .map(DayReport::total_nanos)
+                    .fold(0_f64, |acc, n| acc + n)
+            ),
+        ));
+
+        for day in &self.days {
+            let count = |is_status: fn(Status) -> bool| {
+                day.parts.iter().filter(|p| is_status(p.status)).count()
+            };
+            let (skipped, failures, errors) = (
+                count(|s| s == Status::Unsolved),
+                count(|s| s == Status::Failed),
+                count(Status::is_error),
+            );
+
+            xml.push_str(&format!(
+                "  <testsuite name=\"Day {}\" tests=\"2\" skipped=\"{skipped}\" failures=\"{failures}\" errors=\"{errors}\" time=\"{:.6}\">\n",
+                day.day(),
+                seconds(day.total_nanos()),
+            ));
+
+            for (part, report) in [1, 2].into_iter().zip(&day.parts) {
+                xml.push_str(&format!(
+                    "    <testcase name=\"Part {part}\" classname=\"{}.day{}\" time=\"{:.6}\">\n",
+                    self.year,
+                    day.day(),
+                    seconds(report.nanos.unwrap_or_default()),
+                ));
+
+                match report.status {
+                    Status::Solved => {
+                        let answer = report.answer.as_deref().unwrap_or_default();
+                        xml.push_str(&format!(
+                            "      <system-out>{}</system-out>\n",
+                            escape_xml(answer)
+                        ));
+                    }
+                    Status::Unsolved => xml.push_str("      <skipped message=\"unsolved\"/>\n"),
+                    Status::Failed => {
+                        let error = report.error.as_deref().unwrap_or_default();
+                        xml.push_str(&format!(
+                            "      <failure message=\"{}\"/>\n",
+                            escape_xml(error)
+                        ));
+                    }
+                    Status::Panicked | Status::TimedOut | Status::OutOfMemory => {
+                        xml.push_str(&format!("      <error message=\"{}\"/>\n", report.status));
+                    }
+                }
+
+                xml.push_str("    </testcase>\n");
+            }
+
+            xml.push_str("  </testsuite>\n");
+        }
+
+        xml.push_str("</testsuites>");
+        xml
+    }
+}
+
+fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Report> for JsonValue {
+    fn from(value: &Report) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("year".into(), JsonValue::String(value.year.to_string()));
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.days.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl From<&DayReport> for JsonValue {
+    fn from(value: &DayReport) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day().to_string()));
+        map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos()));
+
+        for (key, part) in [("part_1", &value.parts[0]), ("part_2", &value.parts[1])] {
+            map.insert(key.into(), JsonValue::from(part));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl From<&PartReport> for JsonValue {
+    fn from(value: &PartReport) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("status".into(), JsonValue::String(value.status.to_string()));
+        map.insert(
+            "answer".into(),
+            value
+                .answer
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert(
+            "error".into(),
+            value
+                .error
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert(
+            "nanos".into(),
+            value.nanos.map_or(JsonValue::Null, JsonValue::Number),
+        );
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "samples".into(),
+            value
+                .samples
+                .map_or(JsonValue::Null, |x| JsonValue::Number(x as f64)),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{DayReport, Format, PartReport, Report, Status};
+    use crate::template::timings::Timing;
+    use crate::{day, year};
+
+    fn report() -> Report {
+        Report {
+            year: year!(2025),
+            days: vec![DayReport {
+                timing: Timing {
+                    day: day!(1),
+                    parse: None,
+                    part_1: None,
+                    part_2: None,
+                    total_nanos: 0_f64,
+                    stats: [None, None],
+                    memory: [None, None],
+                    errors: [None, None],
+                    policy: None,
+                },
+                parts: [
+                    PartReport {
+                        answer: Some("<42>".into()),
+                        status: Status::Solved,
+                        error: None,
+                        nanos: Some(1500_f64),
+                        samples: Some(1),
+                    },
+                    PartReport {
+                        answer: None,
+                        status: Status::Panicked,
+                        error: None,
+                        nanos: None,
+                        samples: None,
+                    },
+                ],
+            }],
+        }
+    }
+
+    fn failed_report() -> Report {
+        let mut report = report();
+        report.days[0].parts[1] = PartReport {
+            answer: None,
+            status: Status::Failed,
+            error: Some("expected \"#\" or \".\"".into()),
+            nanos: Some(800_f64),
+            samples: Some(1),
+        };
+        report
+    }
+
+    #[test]
+    fn renders_json() {
+        let json = report().render(Format::Json);
+        let value: tinyjson::JsonValue = json.parse().unwrap();
+        assert_eq!(value["year"], tinyjson::JsonValue::String("2025".into()));
+
+        let day = &value["data"][0];
+        assert_eq!(
+            day["part_1"]["answer"],
+            tinyjson::JsonValue::String("<42>".into())
+        );
+        assert_eq!(day["part_1"]["samples"], tinyjson::JsonValue::Number(1_f64));
+        assert_eq!(
+            day["part_2"]["status"],
+            tinyjson::JsonValue::String("panicked".into())
+        );
+        assert_eq!(day["part_2"]["answer"], tinyjson::JsonValue::Null);
+        assert_eq!(day["part_2"]["error"], tinyjson::JsonValue::Null);
+
+        let json = failed_report().render(Format::Json);
+        let value: tinyjson::JsonValue = json.parse().unwrap();
+        let part = &value["data"][0]["part_2"];
+        assert_eq!(part["status"], tinyjson::JsonValue::String("failed".into()));
+        assert_eq!(
+            part["error"],
+            tinyjson::JsonValue::String("expected \"#\" or \".\"".into())
+        );
+    }
+
+    #[test]
+    fn renders_junit() {
+        let xml = report().render(Format::Junit);
+        assert!(xml.contains(
+            "<testsuites name=\"advent_of_code 2025\" tests=\"2\" skipped=\"0\" failures=\"0\" errors=\"1\""
+        ));
+        assert!(xml.contains("<system-out>&lt;42&gt;</system-out>"));
+        assert!(xml.contains("<error message=\"panicked\"/>"));
+
+        let mut timed_out = report();
+        timed_out.days[0].parts[1].status = Status::TimedOut;
+        let xml = timed_out.render(Format::Junit);
+        assert!(xml.contains("failures=\"0\" errors=\"1\""));
+        assert!(xml.contains("<error message=\"timed out\"/>"));
+
+        let xml = failed_report().render(Format::Junit);
+        assert!(xml.contains("tests=\"2\" skipped=\"0\" failures=\"1\" errors=\"0\""));
+        assert!(xml.contains("<failure message=\"expected &quot;#&quot; or &quot;.&quot;\"/>"));
+    }
+
+    #[test]
+    fn renders_untimed_day() {
+        let mut report = report();
+        for part in &mut report.days[0].parts {
+            part.nanos = None;
+        }
+
+        assert!(report.days[0].total_nanos().is_sign_positive());
+
+        let xml = report.render(Format::Junit);
+        assert!(!xml.contains("-0"), "{xml}");
+        assert!(xml.contains("time=\"0.000000\""));
+
+        let json = report.render(Format::Json);
+        assert!(json.contains("\"total_nanos\":0"), "{json}");
+    }
+
+    #[test]
+    fn parses_formats() {
+        assert_eq!("json".parse::<Format>(), Ok(Format::Json));
+        assert_eq!("junit".parse::<Format>(), Ok(Format::Junit));
+        assert!("xml".parse::<Format>().is_err());
+    }
+}
@@ -1,48 +1,225 @@
-use std::{collections::HashSet, io};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    io,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::Duration,
+};
 
-use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
+use crate::template::memory::format_bytes;
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day, Year, registry};
+
+use super::report::{DayReport, Format, Report};
+use super::timings::{BenchPolicy, Timing};
+use child_commands::ChildOutput;
+
+/// Options for running a set of days.
+#[derive(Clone, Debug)]
+pub struct RunOptions {
+    pub is_release: bool,
+    pub is_timed: bool,
+    pub is_example: bool,
+    /// Number of days that run concurrently.
+    pub jobs: usize,
+    /// Print a machine-readable report instead of the solution output.
+    pub format: Option<Format>,
+    /// How parts are benched when `is_timed` is set.
+    pub bench: BenchPolicy,
+    /// Count the heap usage of each part.
+    pub is_memory: bool,
+    /// Limits every solution bin runs under.
+    pub limits: Limits,
+}
 
-use super::{
-    all_days,
-    timings::{Timing, Timings},
-};
+impl Default for RunOptions {
+    fn default() -> Self {
+        Self {
+            is_release: false,
+            is_timed: false,
+            is_example: false,
+            jobs: 1,
+            format: None,
+            bench: BenchPolicy::default(),
+            is_memory: false,
+            limits: Limits::default(),
+        }
+    }
+}
+
+/// Resource limits of a solution bin, see `--timeout` and `--max-memory`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Limits {
+    /// The bin is killed if it runs longer than this.
+    pub timeout: Option<Duration>,
+    /// Size of the address space of the bin in bytes. Only enforced on Unix.
+    pub max_memory: Option<u64>,
+}
+
+impl Limits {
+    pub fn is_set(&self) -> bool {
+        self.timeout.is_some() || self.max_memory.is_some()
+    }
+}
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+/// The limit a solution bin was stopped for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Exceeded {
+    Timeout,
+    Memory,
+}
 
-    let mut need_space = false;
+impl Display for Exceeded {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Exceeded::Timeout => "timed out",
+            Exceeded::Memory => "out of memory",
+        })
+    }
+}
 
+/// Runs a set of days. Prints output for humans, or the report in `options.format` once all days are done.
+pub fn run_multi(year: Year, days_to_run: &HashSet<Day>, options: &RunOptions) -> Report {
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
+    let days: Vec<Day> = year
+        .days()
         .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
+        .collect();
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+    let is_human = options.format.is_none();
+    let mut reports: Vec<DayReport> = Vec::with_capacity(days.len());
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
-
-            if output.is_empty() {
+    let mut collect_report = |day: Day, output: &ChildOutput| {
+        match output.exceeded {
+            Some(Exceeded::Timeout) if is_human => {
+                let timeout = options.limits.timeout.unwrap_or_default();
+                println!("{ANSI_ITALIC}Timed out after {timeout:?}.{ANSI_RESET}");
+            }
+            Some(Exceeded::Memory) if is_human => {
+                let max_memory = format_bytes(options.limits.max_memory.unwrap_or_default());
+                println!("{ANSI_ITALIC}Out of memory ({max_memory}).{ANSI_RESET}");
+            }
+            // solutions that run in-process print directly instead of into `stdout`.
+            None if is_human && output.stdout.is_empty() && output.records.is_empty() => {
                 println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
             }
-        });
+            _ => {}
+        }
+        let mut report = child_commands::parse_report(output, day);
+        if options.is_timed {
+            report.timing.policy = Some(options.bench);
+        }
+        reports.push(report);
+    };
 
-    if is_timed {
-        let timings = Timings { data: timings };
-        let total_millis = timings.total_millis();
-        println!(
-            "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
-        );
-        Some(timings)
+    if options.jobs <= 1 {
+        for (i, &day) in days.iter().enumerate() {
+            if is_human {
+                print_header(i, day);
+            }
+            let output = run_solution(year, day, options, is_human).unwrap();
+            if !is_human {
+                output.stderr.iter().for_each(|line| eprintln!("{line}"));
+            }
+            collect_report(day, &output);
+        }
     } else {
-        None
+        run_parallel(year, &days, options, |i, day, output| {
+            if is_human {
+                print_header(i, day);
+                output.stdout.iter().for_each(|line| println!("{line}"));
+            }
+            output.stderr.iter().for_each(|line| eprintln!("{line}"));
+            collect_report(day, &output);
+        });
+    }
+
+    let report = Report {
+        year,
+        days: reports,
+    };
+
+    match options.format {
+        Some(format) => println!("{}", report.render(format)),
+        None if options.is_timed => {
+            let total_millis = report.timings().total_millis();
+            println!(
+                "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET} ({})",
+                options.bench
+            );
+        }
+        None => {}
+    }
+
+    report
+}
+
+fn print_header(index: usize, day: Day) {
+    if index > 0 {
+        println!();
+    }
+    println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+    println!("------");
+}
+
+/// Runs up to `options.jobs` days at once with buffered output.
+/// `on_done` receives the finished days in the order of `days`, regardless of which finishes first.
+fn run_parallel(
+    year: Year,
+    days: &[Day],
+    options: &RunOptions,
+    mut on_done: impl FnMut(usize, Day, ChildOutput),
+) {
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..options.jobs.min(days.len()) {
+            let tx = tx.clone();
+            let next = &next;
+            scope.spawn(move || {
+                loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(&day) = days.get(i) else {
+                        break;
+                    };
+                    let output = run_solution(year, day, options, false).unwrap();
+                    tx.send((i, output)).unwrap();
+                }
+            });
+        }
+        drop(tx);
+
+        let mut finished: HashMap<usize, ChildOutput> = HashMap::new();
+        let mut next_to_print = 0;
+
+        for (i, output) in rx {
+            finished.insert(i, output);
+            while let Some(output) = finished.remove(&next_to_print) {
+                on_done(next_to_print, days[next_to_print], output);
+                next_to_print += 1;
+            }
+        }
+    });
+}
+
+/// Run the solution of a day. If it was compiled into the running binary, see [`registry`], it is
+/// called in-process when its output goes straight to the terminal. Output that has to be buffered
+/// and limits need a process of their own, so the solution is spawned otherwise.
+pub fn run_solution(
+    year: Year,
+    day: Day,
+    options: &RunOptions,
+    echo: bool,
+) -> Result<ChildOutput, Error> {
+    match registry::find(year, day) {
+        Some(registered) if echo && !options.limits.is_set() => {
+            Ok(in_process::run_solution(registered, options))
+        }
+        _ => child_commands::run_solution(year, day, options, echo),
     }
 }
 
@@ -59,48 +236,97 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
-/// All solutions live in isolated binaries.
+/// Every solution lives in an isolated binary.
 /// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
 pub mod child_commands {
-    use super::{Error, get_path_for_bin};
-    use crate::template::Day;
+    use super::{Error, Exceeded, RunOptions};
+    use crate::template::protocol::{RECORDS_ENV, Record, RecordsFile};
+    use crate::template::report::{DayReport, PartReport, Status};
+    use crate::template::timings::format_nanos;
+    use crate::template::{Day, Year, get_bin_name, get_path_for_bin, registry};
     use std::{
-        io::{BufRead, BufReader},
-        path::Path,
+        collections::HashMap,
+        env,
+        io::{self, BufRead, BufReader},
+        path::{Path, PathBuf},
         process::{Command, Stdio},
         thread,
+        time::{Duration, Instant},
     };
+    use tinyjson::JsonValue;
+
+    /// Lines a solution bin printed to stdout and stderr, and the records it reported.
+    #[derive(Debug)]
+    pub struct ChildOutput {
+        pub stdout: Vec<String>,
+        pub stderr: Vec<String>,
+        pub records: Vec<Record>,
+        /// `false` if the bin panicked or failed to compile.
+        pub success: bool,
+        /// The limit the bin was stopped for, if any.
+        pub exceeded: Option<Exceeded>,
+    }
+
+    impl Default for ChildOutput {
+        fn default() -> Self {
+            Self {
+                stdout: vec![],
+                stderr: vec![],
+                records: vec![],
+                success: true,
+                exceeded: None,
+            }
+        }
+    }
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    /// Run the solution bin for a given day.
+    /// With `echo`, output is forwarded while it is read, otherwise it is only buffered.
+    ///
+    /// Days that are compiled into the running binary are started from it, so cargo does not check
+    /// their build again, see [`registry`]. Other days are started with `cargo run`.
+    ///
+    /// With [`Limits`](super::Limits), the bin is built first and started without cargo, so the
+    /// limits apply to the solution only: it is killed once it runs longer than the timeout, and
+    /// its address space is capped at the memory limit.
+    pub fn run_solution(
+        year: Year,
+        day: Day,
+        options: &RunOptions,
+        echo: bool,
+    ) -> Result<ChildOutput, Error> {
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+        if !Path::new(&get_path_for_bin(year, day)).exists() {
+            return Ok(ChildOutput::default());
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let bin_name = get_bin_name(year, day);
 
-        if is_release {
-            args.push("--release");
-        }
+        let mut command = if registry::find(year, day).is_some() {
+            let mut command = limited_command(env::current_exe()?, options.limits.max_memory);
+            command.args([registry::RUN_ARG, &bin_name]);
+            command
+        } else if options.limits.is_set() {
+            match build_bin(&bin_name, options, echo)? {
+                Ok(executable) => limited_command(executable, options.limits.max_memory),
+                Err(output) => return Ok(output),
+            }
+        } else {
+            let mut command = Command::new("cargo");
+            command.args(["run", "--quiet", "--bin", &bin_name]);
+            command.args(build_args(options));
+            command.arg("--");
+            command
+        };
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
-        }
+        command.args(solution_args(options));
 
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // grab stdout and stderr lines, forwarding them if requested.
+
+        let records = RecordsFile::new(year, day);
 
-        let mut cmd = Command::new("cargo")
-            .args(&args)
+        let mut cmd = command
+            .env(RECORDS_ENV, records.path())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()?;
@@ -108,85 +334,295 @@ pub mod child_commands {
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
+        let mut output = ChildOutput::default();
+
+        let stdout_thread =
+            thread::spawn(move || read_lines(stdout, echo, |line| println!("{line}")));
+        let stderr_thread =
+            thread::spawn(move || read_lines(stderr, echo, |line| eprintln!("{line}")));
+
+        let status = match options.limits.timeout {
+            Some(timeout) => {
+                let deadline = Instant::now() + timeout;
+                loop {
+                    if let Some(status) = cmd.try_wait()? {
+                        break status;
+                    }
+                    if Instant::now() >= deadline {
+                        cmd.kill()?;
+                        output.exceeded = Some(Exceeded::Timeout);
+                        break cmd.wait()?;
+                    }
+                    thread::sleep(Duration::from_millis(10));
+                }
+            }
+            None => cmd.wait()?,
+        };
+
+        output.stdout = stdout_thread.join().unwrap();
+        output.stderr = stderr_thread.join().unwrap();
+        output.success = status.success();
+        output.records = records.read();
+
+        // the standard library aborts with this message if an allocation fails.
+        if !output.success
+            && output.exceeded.is_none()
+            && output
+                .stderr
+                .iter()
+                .any(|line| line.starts_with("memory allocation of"))
+        {
+            output.exceeded = Some(Exceeded::Memory);
+        }
+
+        Ok(output)
+    }
+
+    fn read_lines(reader: impl BufRead, echo: bool, forward: impl Fn(&str)) -> Vec<String> {
+        reader
+            .lines()
+            .map(|line| {
+                let line = line.unwrap();
+                if echo {
+                    forward(&line);
+                }
+                line
+            })
+            .collect()
+    }
+
+    /// Build a solution bin and return the path of its executable, see [`build_executable`].
+    fn build_bin(
+        bin_name: &str,
+        options: &RunOptions,
+        echo: bool,
+    ) -> Result<Result<PathBuf, ChildOutput>, Error> {
+        let mut command = Command::new("cargo");
+        command.args([
+            "build",
+            "--quiet",
+            "--bin",
+            bin_name,
+            "--message-format=json-render-diagnostics",
+        ]);
+        command.args(build_args(options));
+
+        build_executable(command, echo)
+    }
+
+    /// Build the main binary with every solution compiled in, see [`registry`], and return the
+    /// path of its executable. `features` are enabled for the whole binary.
+    pub fn build_main_binary(
+        options: &RunOptions,
+        features: &[String],
+    ) -> Result<Result<PathBuf, ChildOutput>, Error> {
+        let mut command = Command::new("cargo");
+        command.args([
+            "rustc",
+            "--quiet",
+            "--bin",
+            env!("CARGO_PKG_NAME"),
+            "--features",
+            "registry",
+            "--message-format=json-render-diagnostics",
+        ]);
+        command.args(build_args(options));
+        command.arg("--");
+        command.args(
+            features
+                .iter()
+                .map(|feature| format!("-Zcrate-attr=feature({feature})")),
+        );
+
+        build_executable(command, false)
+    }
+
+    /// Run a cargo build with JSON messages and return the path of the executable it built. If the
+    /// build fails, the compiler output is returned as the output of the day instead.
+    fn build_executable(
+        mut command: Command,
+        echo: bool,
+    ) -> Result<Result<PathBuf, ChildOutput>, Error> {
+        let build = command.stderr(Stdio::piped()).output()?;
+
+        let stderr: Vec<String> = String::from_utf8_lossy(&build.stderr)
+            .lines()
+            .map(str::to_string)
+            .collect();
+
+        if echo {
+            stderr.iter().for_each(|line| eprintln!("{line}"));
+        }
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
+        // cargo reports each built artifact as a JSON message, bins with the path of their executable.
+        let executable = String::from_utf8_lossy(&build.stdout)
+            .lines()
+            .filter_map(|line| line.parse::<JsonValue>().ok())
+            .find_map(|message| {
+                message
+                    .get::<HashMap<String, JsonValue>>()?
+                    .get("executable")?
+                    .get::<String>()
+                    .cloned()
             });
-        });
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+        match executable {
+            Some(executable) if build.status.success() => Ok(Ok(PathBuf::from(executable))),
+            _ => Ok(Err(ChildOutput {
+                stderr,
+                success: false,
+                ..ChildOutput::default()
+            })),
         }
+    }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+    /// A command that starts the executable with its address space capped at `max_memory` bytes.
+    fn limited_command(executable: PathBuf, max_memory: Option<u64>) -> Command {
+        let mut command = Command::new(executable);
+
+        #[cfg(unix)]
+        if let Some(max_memory) = max_memory {
+            use std::os::unix::process::CommandExt;
+
+            let limit = libc::rlimit {
+                rlim_cur: max_memory as libc::rlim_t,
+                rlim_max: max_memory as libc::rlim_t,
+            };
+
+            // SAFETY: `setrlimit` is async-signal-safe and only touches the limits of the child.
+            unsafe {
+                command.pre_exec(move || {
+                    if libc::setrlimit(libc::RLIMIT_AS, &limit) == 0 {
+                        Ok(())
+                    } else {
+                        Err(io::Error::last_os_error())
+                    }
+                });
+            }
+        }
 
-        Ok(output)
+        #[cfg(not(unix))]
+        let _ = max_memory;
+
+        command
+    }
+
+    /// Arguments passed to cargo to build a solution bin for the options of the run.
+    fn build_args(options: &RunOptions) -> Vec<&'static str> {
+        let mut args = vec![];
+
+        if options.is_release {
+            args.push("--release");
+        }
+
+        // only bins built with this feature count their heap usage, see `memory`.
+        if options.is_memory {
+            args.extend(["--features", "count-memory"]);
+        }
+
+        args
+    }
+
+    /// Arguments passed to a solution for the options of the run.
+    pub fn solution_args(options: &RunOptions) -> Vec<String> {
+        let mut args = vec![];
+
+        if options.is_timed {
+            // mirror `--time` flag to child invocations.
+            args.push("--time".into());
+            args.extend(options.bench.to_args());
+        }
+
+        if options.is_memory {
+            args.push("--memory".into());
+        }
+
+        if options.is_example {
+            args.push("--example".into());
+        }
+
+        args
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
+    /// Collect answers, durations and the status of both parts from the records of a solution bin.
+    pub fn parse_report(output: &ChildOutput, day: Day) -> DayReport {
+        let mut timing = super::Timing {
             day,
+            parse: None,
             part_1: None,
             part_2: None,
             total_nanos: 0_f64,
+            stats: [None, None],
+            memory: [None, None],
+            errors: [None, None],
+            policy: None,
         };
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
-
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+        // a part without a record never ran, either because the bin failed or the part is not implemented.
+        let mut parts = [0, 1].map(|_| PartReport {
+            answer: None,
+            status: match output.exceeded {
+                Some(Exceeded::Timeout) => Status::TimedOut,
+                Some(Exceeded::Memory) => Status::OutOfMemory,
+                None if output.success => Status::Unsolved,
+                None => Status::Panicked,
+            },
+            error: None,
+            nanos: None,
+            samples: None,
+        });
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+        for record in &output.records {
+            match record {
+                Record::Parse(measurement) => {
+                    // only the first parse counts, parts on different inputs are parsed again.
+                    if measurement.stats.is_some() && timing.parse.is_none() {
+                        timing.parse = Some(format_nanos(measurement.nanos));
+                        timing.total_nanos += measurement.nanos;
+                    }
                 }
+                Record::Part {
+                    part,
+                    answer,
+                    error,
+                    measurement,
+                } => {
+                    let index = usize::from(*part - 1);
+
+                    parts[index] = PartReport {
+                        answer: answer.clone(),
+                        status: match (answer, error) {
+                            (Some(_), _) => Status::Solved,
+                            (None, Some(_)) => Status::Failed,
+                            (None, None) => Status::Unsolved,
+                        },
+                        error: error.clone(),
+                        nanos: Some(measurement.nanos),
+                        samples: Some(measurement.samples),
+                    };
+
+                    timing.memory[index] = measurement.memory;
+                    timing.errors[index] = error.clone();
+
+                    // only benched parts with an answer are timings worth storing, a failed part
+                    // keeps its error instead.
+                    if let Some(stats) = measurement.stats
+                        && answer.is_some()
+                    {
+                        let time = Some(format_nanos(measurement.nanos));
+                        if index == 0 {
+                            timing.part_1 = time;
+                        } else {
+                            timing.part_2 = time;
+                        }
+                        timing.stats[index] = Some(stats);
+                        timing.total_nanos += measurement.nanos;
+                    }
+                }
+            }
+        }
 
-                timings.total_nanos += nanos;
-            });
-
-        timings
-    }
-
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
-    }
-
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .next_back()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+        DayReport { timing, parts }
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -205,53 +641,200 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use super::{ChildOutput, parse_report};
+        use crate::template::protocol::{Measurement, Record};
+        use crate::template::report::Status;
+        use crate::template::run_multi::Exceeded;
+        use crate::template::timings::Stats;
 
         use crate::day;
 
+        fn measurement(nanos: f64, samples: u128) -> Measurement {
+            Measurement {
+                nanos,
+                samples,
+                stats: (samples > 1).then_some(Stats {
+                    mean: nanos,
+                    min: nanos,
+                    median: nanos,
+                    p95: nanos,
+                    max: nanos,
+                    stddev: 0_f64,
+                }),
+                memory: None,
+            }
+        }
+
+        fn part(part: u8, answer: Option<&str>, nanos: f64, samples: u128) -> Record {
+            Record::Part {
+                part,
+                answer: answer.map(str::to_string),
+                error: None,
+                measurement: measurement(nanos, samples),
+            }
+        }
+
+        fn output(records: Vec<Record>, success: bool) -> ChildOutput {
+            ChildOutput {
+                records,
+                success,
+                ..ChildOutput::default()
+            }
+        }
+
         #[test]
         fn parses_execution_times() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
+            let res = parse_report(
+                &output(
+                    vec![
+                        part(1, Some("0"), 74.13_f64, 100_000),
+                        part(2, Some("10"), 74_130_000_f64, 99_999),
+                    ],
+                    true,
+                ),
+                day!(1),
+            );
+            assert_approx_eq!(res.timing.total_nanos, 74130074.13_f64);
+            assert_eq!(res.timing.part_1.unwrap(), "74.0ns");
+            assert_eq!(res.timing.part_2.unwrap(), "74.1ms");
+            assert_approx_eq!(res.timing.stats[1].unwrap().mean, 74_130_000_f64);
+        }
+
+        #[test]
+        fn parses_parse_times() {
+            let res = parse_report(
+                &output(
+                    vec![
+                        Record::Parse(measurement(1_000_000_f64, 100)),
+                        part(1, Some("0"), 2_000_000_f64, 100),
+                        Record::Parse(measurement(5_000_000_f64, 100)),
+                        part(2, Some("10"), 3_000_000_f64, 100),
+                    ],
+                    true,
+                ),
+                day!(1),
+            );
+            assert_eq!(res.timing.parse.unwrap(), "1.0ms");
+            assert_eq!(res.timing.part_1.unwrap(), "2.0ms");
+            assert_approx_eq!(res.timing.total_nanos, 6000000_f64);
+        }
+
+        #[test]
+        fn parses_answers() {
+            let res = parse_report(
+                &output(
+                    vec![
+                        part(1, Some("0 (1s)"), 1_000_000_f64, 10),
+                        part(2, Some("#..#\n.##."), 1_000_000_f64, 10),
+                    ],
+                    true,
+                ),
+                day!(1),
+            );
+            assert_eq!(res.parts[0].answer.as_deref(), Some("0 (1s)"));
+            assert_eq!(res.parts[1].answer.as_deref(), Some("#..#\n.##."));
+        }
+
+        #[test]
+        fn parses_report() {
+            let res = parse_report(
+                &output(vec![part(1, Some("42"), 1_200_000_f64, 10)], false),
+                day!(1),
+            );
+            assert_eq!(res.parts[0].status, Status::Solved);
+            assert_eq!(res.parts[0].samples, Some(10));
+            assert_approx_eq!(res.parts[0].nanos.unwrap(), 1200000_f64);
+            assert_eq!(res.parts[1].status, Status::Panicked);
+            assert_eq!(res.timing.part_1.unwrap(), "1.2ms");
+        }
+
+        #[test]
+        fn parses_unsolved_report() {
+            let res = parse_report(
+                &output(
+                    vec![part(1, Some("7"), 19_f64, 1), part(2, None, 5_f64, 1)],
+                    true,
+                ),
+                day!(1),
+            );
+            assert_eq!(res.parts[0].samples, Some(1));
+            assert_approx_eq!(res.parts[0].nanos.unwrap(), 19_f64);
+            assert_eq!(res.parts[1].status, Status::Unsolved);
+            assert!(res.timing.part_1.is_none());
+            assert_approx_eq!(res.timing.total_nanos, 0_f64);
+        }
+
+        #[test]
+        fn parses_benched_unsolved_report() {
+            let res = parse_report(
+                &output(
+                    vec![part(1, Some("7"), 19_f64, 10), part(2, None, 5_f64, 10)],
+                    true,
+                ),
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_eq!(res.parts[1].status, Status::Unsolved);
+            assert_eq!(res.timing.part_1.as_deref(), Some("19.0ns"));
+            assert!(res.timing.part_2.is_none());
+            assert!(res.timing.stats[1].is_none());
+            assert_approx_eq!(res.timing.total_nanos, 19_f64);
         }
 
         #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
+        fn parses_exceeded_report() {
+            let res = parse_report(
+                &ChildOutput {
+                    exceeded: Some(Exceeded::Timeout),
+                    ..output(vec![part(1, Some("7"), 19_f64, 1)], false)
+                },
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_eq!(res.parts[0].status, Status::Solved);
+            assert_eq!(res.parts[1].status, Status::TimedOut);
         }
 
         #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
+        fn parses_failed_report() {
+            let failed = Record::Part {
+                part: 2,
+                answer: None,
+                error: Some("no path found".into()),
+                measurement: measurement(5_f64, 10),
+            };
+            let res = parse_report(
+                &output(vec![part(1, Some("7"), 19_f64, 1), failed], true),
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
+            assert_eq!(res.parts[1].status, Status::Failed);
+            assert_eq!(res.parts[1].error.as_deref(), Some("no path found"));
+            assert_eq!(res.timing.errors, [None, Some("no path found".into())]);
+            assert!(res.timing.part_2.is_none());
+        }
+    }
+}
+
+/// Solutions compiled into the running binary, see [`registry`].
+/// This module calls them on the current thread, their output goes straight to the terminal.
+mod in_process {
+    use super::RunOptions;
+    use super::child_commands::{ChildOutput, solution_args};
+    use crate::template::registry::RegisteredDay;
+    use crate::template::{get_bin_name, protocol, runner};
+    use std::panic;
+
+    /// Run a registered solution. Its answers and timings are handed over as records.
+    pub fn run_solution(registered: &RegisteredDay, options: &RunOptions) -> ChildOutput {
+        let mut args = vec![get_bin_name(registered.year, registered.day)];
+        args.extend(solution_args(options));
+
+        // a panicking solution fails like a panicking bin, the panic message is printed by the hook.
+        let (result, records) =
+            protocol::collect(|| runner::with_args(args, || panic::catch_unwind(registered.run)));
+
+        ChildOutput {
+            records,
+            success: result.is_ok(),
+            ..ChildOutput::default()
         }
     }
 }
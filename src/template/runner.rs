@@ -1,63 +1,336 @@
 /// Encapsulates code that interacts with solution functions.
+use std::cell::RefCell;
 use std::fmt::Display;
 use std::hint::black_box;
-use std::io::{Write, stdout};
-use std::process::Output;
+use std::io::{Read, Write, stdin, stdout};
+use std::path::Path;
+use std::sync::OnceLock;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{env, process};
 
 use crate::template::ANSI_BOLD;
-use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
+use crate::template::answers::Answers;
+use crate::template::aoc_client::AocCommandError;
+use crate::template::memory;
+use crate::template::protocol::{self, Measurement, Record};
+use crate::template::solution::{PartOutput, PartResult};
+use crate::template::submissions::{Submission, Submissions, Verdict};
+use crate::template::timings::{BenchPolicy, STATS_PREFIX, Stats, format_nanos, parse_duration};
+use crate::template::{
+    ANSI_ITALIC, ANSI_RESET, Day, Solution, Year, aoc_client, get_data_dir, read_file,
+    read_file_part,
+};
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
-    let part_str = format!("Part {part}");
+thread_local! {
+    /// Arguments of a solution that runs in the main binary, see [`with_args`].
+    static ARGS: RefCell<Option<Vec<String>>> = const { RefCell::new(None) };
+}
+
+/// Run a solution on the current thread as if its binary was started with `args`.
+/// `args` starts with the program name, like [`env::args`].
+pub fn with_args<T>(args: Vec<String>, func: impl FnOnce() -> T) -> T {
+    let previous = ARGS.replace(Some(args));
+    let result = func();
+    ARGS.set(previous);
+    result
+}
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+/// The arguments of the solution: those passed to [`with_args`] or to the binary otherwise.
+fn args() -> Vec<String> {
+    ARGS.with_borrow(Clone::clone)
+        .unwrap_or_else(|| env::args().collect())
+}
+
+/// Returns the path passed with `--input`, if any. `-` stands for stdin.
+fn input_arg() -> Option<String> {
+    let mut args = args().into_iter().skip_while(|x| x != "--input");
+    args.next()?;
+    let Some(path) = args.next() else {
+        eprintln!("Unexpected command-line input. Format: cargo solve 1 --input <path>");
+        process::exit(1);
+    };
+    Some(path)
+}
+
+/// Returns the example selected with `--example [N]`. Without `N`, each part picks its own example.
+fn example_arg() -> Option<Option<u8>> {
+    let mut args = args().into_iter().skip_while(|x| x != "--example");
+    args.next()?;
+    Some(args.next().and_then(|n| n.parse().ok()))
+}
+
+/// Returns the [`BenchPolicy`] passed with `--bench-time`, `--min-samples`, `--max-samples` and `--warmup`.
+fn bench_policy_arg() -> BenchPolicy {
+    let value = |name: &str| {
+        let mut args = args().into_iter().skip_while(|x| x != name);
+        args.next()?;
+        let Some(value) = args.next() else {
+            eprintln!("Unexpected command-line input. Format: {name} <value>");
+            process::exit(1);
+        };
+        Some(value)
+    };
+
+    let count = |name: &str, default: u128| {
+        value(name).map_or(default, |n| {
+            n.parse().unwrap_or_else(|_| {
+                eprintln!("Unexpected command-line input. Expecting {name} to be a number.");
+                process::exit(1);
+            })
+        })
+    };
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    let default = BenchPolicy::default();
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    let policy = BenchPolicy {
+        bench_time: value("--bench-time").map_or(default.bench_time, |s| {
+            parse_duration(&s).unwrap_or_else(|e| {
+                eprintln!("{e}");
+                process::exit(1);
+            })
+        }),
+        min_samples: count("--min-samples", default.min_samples),
+        max_samples: count("--max-samples", default.max_samples),
+        warmup: count("--warmup", default.warmup),
+    };
+
+    if let Err(e) = policy.validate() {
+        eprintln!("Unexpected command-line input: {e}.");
+        process::exit(1);
     }
+
+    policy
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
-fn run_timed<I: Copy, T>(
-    func: impl Fn(I) -> T,
+/// Read the input of a solution part:
+///  1. an example for `--example [N]`, see [`read_example`].
+///  2. the file passed with `--input`, or stdin for `--input -`.
+///  3. the day's puzzle input from `data/<year>/inputs` otherwise.
+///
+/// `--example` and `--input` cannot be combined.
+pub fn read_input(year: Year, day: Day, part: u8) -> String {
+    static STDIN: OnceLock<String> = OnceLock::new();
+
+    let input = input_arg();
+
+    if let Some(example) = example_arg() {
+        if input.is_some() {
+            eprintln!(
+                "Unexpected command-line input. `--input` cannot be combined with `--example`."
+            );
+            process::exit(1);
+        }
+        return read_example(year, day, example, part);
+    }
+
+    match input.as_deref() {
+        None => read_file("inputs", year, day),
+        Some("-") => STDIN
+            .get_or_init(|| {
+                let mut input = String::new();
+                stdin()
+                    .read_to_string(&mut input)
+                    .expect("could not read input from stdin");
+                input
+            })
+            .clone(),
+        Some(path) => std::fs::read_to_string(path).expect("could not open input file"),
+    }
+}
+
+/// Read an example from `data/<year>/examples`. Example `N` is stored as `NN-N.txt`.
+/// When no example is selected, a part uses its own example (e.g. `11-2.txt` for part two) if present.
+/// Both fall back to the default example `NN.txt` if no numbered one exists, except for an explicit `N > 1`.
+fn read_example(year: Year, day: Day, example: Option<u8>, part: u8) -> String {
+    let n = example.unwrap_or(part);
+    let numbered = Path::new(&get_data_dir("examples", year)).join(format!("{day}-{n}.txt"));
+
+    if numbered.exists() || example.is_some_and(|n| n > 1) {
+        read_file_part("examples", year, day, n)
+    } else {
+        read_file("examples", year, day)
+    }
+}
+
+/// Run the parts of a solution that works on the input directly. Each part reads its own input.
+pub fn run_parts<S: Solution>(solution: &S, parts: &[u8]) {
+    let (year, day) = (solution.year(), solution.day());
+
+    for &part in parts {
+        let input = read_input(year, day, part);
+        let input = input.as_str();
+
+        match part {
+            1 => run_output(
+                |i| solution.part_one(&solution.parse(i)),
+                input,
+                year,
+                day,
+                part,
+            ),
+            _ => run_output(
+                |i| solution.part_two(&solution.parse(i)),
+                input,
+                year,
+                day,
+                part,
+            ),
+        }
+    }
+}
+
+/// Run the parser of a solution once and its parts on the parsed value.
+pub fn run_parsed<S: Solution>(solution: &S, parts: &[u8]) {
+    let (year, day) = (solution.year(), solution.day());
+
+    let inputs: Vec<String> = parts
+        .iter()
+        .map(|&part| read_input(year, day, part))
+        .collect();
+
+    // parts share the parsed value unless they run on different inputs, e.g. examples.
+    let mut parsed = vec![];
+    let mut parsed_indices = vec![];
+    for (i, input) in inputs.iter().enumerate() {
+        match inputs[..i].iter().position(|other| other == input) {
+            Some(j) => parsed_indices.push(parsed_indices[j]),
+            None => {
+                parsed_indices.push(parsed.len());
+                parsed.push(run_parse(|i| solution.parse(i), input.as_str()));
+            }
+        }
+    }
+
+    for (&part, index) in parts.iter().zip(parsed_indices) {
+        let parsed = &parsed[index];
+
+        match part {
+            1 => run_output(|p| solution.part_one(p), parsed, year, day, part),
+            _ => run_output(|p| solution.part_two(p), parsed, year, day, part),
+        }
+    }
+}
+
+/// Run a part that returns an `Option` or a `Result`, see [`PartResult`].
+pub fn run_part<I: Copy, R: PartResult>(
+    func: impl Fn(I) -> R,
     input: I,
-    hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+    year: Year,
+    day: Day,
+    part: u8,
+) {
+    run_output(|i| func(i).into_output(), input, year, day, part);
+}
+
+fn run_output<I: Copy, T: Display>(
+    func: impl Fn(I) -> PartOutput<T>,
+    input: I,
+    year: Year,
+    day: Day,
+    part: u8,
+) {
+    let part_str = format!("Part {part}");
+
+    let (result, measurement) = run_timed(func, input, |result| {
+        print_result(result, &part_str, "", None)
+    });
+
+    print_result(
+        &result,
+        &part_str,
+        &format_duration(&measurement),
+        Some(&measurement),
+    );
+
+    protocol::emit(&Record::Part {
+        part,
+        answer: result
+            .as_ref()
+            .ok()
+            .and_then(|a| a.as_ref().map(ToString::to_string)),
+        error: result.as_ref().err().cloned(),
+        measurement,
+    });
+
+    if let Ok(Some(result)) = result {
+        submit_result(result, year, day, part);
+    }
+}
+
+/// Run the parser shared by both parts and report its time like a part's.
+pub fn run_parse<'a, P>(func: impl Fn(&'a str) -> P, input: &'a str) -> P {
+    let (parsed, measurement) = run_timed(func, input, |_| print!("Parse:"));
+
+    print!("\r");
+    println!("Parse:{}", format_duration(&measurement));
+    print_details(Some(&measurement));
+
+    protocol::emit(&Record::Parse(measurement));
+
+    parsed
+}
+
+/// Run a solution part. The behavior differs depending on whether we are running with `--time`:
+///  1. without, the function is executed once.
+///  2. with, the function is benched according to the [`BenchPolicy`] (by default approx. 1 second of execution time or 10 samples, whatever take longer.)
+///
+/// With `--memory`, the heap usage of the first execution is counted as well.
+#[allow(clippy::cast_precision_loss)]
+fn run_timed<I: Copy, T>(func: impl Fn(I) -> T, input: I, hook: impl Fn(&T)) -> (T, Measurement) {
+    // the counting allocator is only installed with `count-memory`, and dhat replaces it.
+    let count_memory = cfg!(feature = "count-memory")
+        && !cfg!(feature = "dhat-heap")
+        && args().iter().any(|x| x == "--memory");
+
     let timer = Instant::now();
-    let result = {
+    let (result, memory) = {
         #[cfg(feature = "dhat-heap")]
         let _profiler = dhat::Profiler::new_heap();
 
-        func(input)
+        if count_memory {
+            let (result, memory) = memory::measure(|| func(input));
+            (result, Some(memory))
+        } else {
+            (func(input), None)
+        }
     };
     let base_time = timer.elapsed();
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    let (duration, samples, stats) = if args().iter().any(|x| x == "--time") {
+        let (duration, samples, stats) = bench(func, input, &base_time, &bench_policy_arg());
+        (duration, samples, Some(stats))
     } else {
-        (base_time, 1)
+        (base_time, 1, None)
     };
 
-    (result, run.0, run.1)
+    let measurement = Measurement {
+        nanos: duration.as_nanos() as f64,
+        samples,
+        stats,
+        memory,
+    };
+
+    (result, measurement)
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+fn bench<I: Copy, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    policy: &BenchPolicy,
+) -> (Duration, u128, Stats) {
     let mut stdout = stdout();
 
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
     let _ = stdout.flush();
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+    for _ in 0..policy.warmup {
+        black_box(func(black_box(input)));
+    }
+
+    let bench_iterations = policy.samples(*base_time);
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -71,6 +344,7 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
         #[allow(clippy::cast_possible_truncation)]
         Duration::from_nanos(average_duration(&timers) as u64),
         bench_iterations,
+        Stats::from_samples(&timers),
     )
 }
 
@@ -82,19 +356,27 @@ fn average_duration(numbers: &[Duration]) -> u128 {
         / numbers.len() as u128
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
+fn format_duration(measurement: &Measurement) -> String {
+    let duration = format_nanos(measurement.nanos);
+
+    if measurement.samples == 1 {
+        format!(" ({duration})")
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        format!(" ({duration} @ {} samples)", measurement.samples)
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+/// Print the result of a part. Benched parts get their [`Stats`] and [`MemoryUsage`](memory::MemoryUsage) on the lines below.
+fn print_result<T: Display>(
+    result: &PartOutput<T>,
+    part: &str,
+    duration_str: &str,
+    measurement: Option<&Measurement>,
+) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
-        Some(result) => {
+        Ok(Some(result)) => {
             if result.to_string().contains('\n') {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
@@ -102,6 +384,7 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 } else {
                     print!("\r");
                     println!("{str}");
+                    print_details(measurement);
                     println!("{result}");
                 }
             } else {
@@ -111,10 +394,11 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 } else {
                     print!("\r");
                     println!("{str}");
+                    print_details(measurement);
                 }
             }
         }
-        None => {
+        Ok(None) => {
             if is_intermediate_result {
                 print!("{part}: ✖");
             } else {
@@ -122,18 +406,47 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 println!("{part}: ✖             ");
             }
         }
+        // the error takes the place of the marker.
+        Err(error) => {
+            let str = format!("{part}: {ANSI_ITALIC}{error}{ANSI_RESET}");
+            if is_intermediate_result {
+                print!("{str}");
+            } else {
+                print!("\r");
+                println!("{str}             ");
+            }
+        }
+    }
+}
+
+fn print_details(measurement: Option<&Measurement>) {
+    let Some(measurement) = measurement else {
+        return;
+    };
+
+    if let Some(stats) = measurement.stats {
+        println!("{STATS_PREFIX}{stats}");
+    }
+
+    if let Some(memory) = measurement.memory {
+        println!("{STATS_PREFIX}{memory}");
     }
 }
 
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
+///  2. a session cookie is configured.
+///  3. the answer was not ruled out by a previous submission.
+///
+/// Every submission the server judges is recorded in `data/submissions.json`,
+/// correct answers are also stored in `data/answers.json` for `cargo verify`.
 fn submit_result<T: Display>(
     result: T,
+    year: Year,
     day: Day,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
+) -> Option<Result<String, AocCommandError>> {
+    let args = args();
 
     if !args.contains(&"--submit".into()) {
         return None;
@@ -155,13 +468,63 @@ fn submit_result<T: Display>(
         return None;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
+    if input_arg().is_some() || example_arg().is_some() {
+        eprintln!("Not submitting: the result was computed from a custom input.");
+        return None;
+    }
+
+    if let Err(e) = aoc_client::check() {
+        eprintln!("{e}");
         process::exit(1);
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    let answer = result.to_string();
+    let mut submissions = match Submissions::read_from_file() {
+        Ok(submissions) => submissions,
+        Err(e) => {
+            eprintln!("Not submitting `{answer}`: {e}");
+            return None;
+        }
+    };
+
+    if let Err(refusal) = submissions.check(year, day, part, &answer) {
+        eprintln!("Not submitting `{answer}`: {refusal}");
+        return None;
+    }
+
+    println!("Submitting result...");
+    let submission = aoc_client::submit(year, day, part, &answer);
+
+    let verdict = match &submission {
+        Ok(message) => Verdict::from_message(message),
+        Err(AocCommandError::RateLimited(_)) => Some(Verdict::Wait),
+        Err(_) => None,
+    };
+
+    if let Err(e) = &submission {
+        eprintln!("failed to submit result: {e}");
+    }
+
+    if verdict == Some(Verdict::Correct) {
+        match Answers::read_from_file() {
+            Ok(mut answers) => {
+                answers.set(year, day, part, &answer);
+                if let Err(e) = answers.store_file() {
+                    eprintln!("failed to store answer: {e}");
+                }
+            }
+            Err(e) => eprintln!("failed to store answer: {e}"),
+        }
+    }
+
+    if let Some(verdict) = verdict {
+        submissions
+            .data
+            .push(Submission::now(year, day, part, &answer, verdict));
+        if let Err(e) = submissions.store_file() {
+            eprintln!("failed to record submission: {e}");
+        }
+    }
+
+    Some(submission)
 }
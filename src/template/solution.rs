@@ -0,0 +1,251 @@
+/// Module for the typed core of a day, which [`solution!`](crate::solution) generates from the
+/// functions of a solution module.
+use std::{convert::Infallible, fmt::Display};
+
+use crate::template::{Day, Year};
+
+/// What a part returned: the answer, `Ok(None)` if it has none, or the error message if it failed.
+pub type PartOutput<T> = Result<Option<T>, String>;
+
+/// Types a part can return: `Option<T>`, or `Result<T, E>` to report why it has no answer.
+pub trait PartResult {
+    type Answer: Display;
+
+    fn into_output(self) -> PartOutput<Self::Answer>;
+}
+
+impl<T: Display> PartResult for Option<T> {
+    type Answer = T;
+
+    fn into_output(self) -> PartOutput<T> {
+        Ok(self)
+    }
+}
+
+impl<T: Display, E: Display> PartResult for Result<T, E> {
+    type Answer = T;
+
+    fn into_output(self) -> PartOutput<T> {
+        self.map(Some).map_err(|e| e.to_string())
+    }
+}
+
+/// A day of Advent of Code: a parser shared by both parts and the parts themselves.
+///
+/// Tooling such as runners, verifiers and benchmarks can be written once for every `S: Solution`.
+/// `solution!` implements it through [`Parts`] or [`ParsedParts`]. A solution can implement it by
+/// hand as well, e.g. to return a parsed value that borrows from the input.
+pub trait Solution {
+    /// The input after parsing, shared by both parts.
+    type Parsed<'a>;
+    /// The answer of part one.
+    type Answer1: Display;
+    /// The answer of part two.
+    type Answer2: Display;
+
+    fn year(&self) -> Year;
+
+    fn day(&self) -> Day;
+
+    fn parse<'a>(&self, input: &'a str) -> Self::Parsed<'a>;
+
+    fn part_one(&self, parsed: &Self::Parsed<'_>) -> PartOutput<Self::Answer1>;
+
+    fn part_two(&self, parsed: &Self::Parsed<'_>) -> PartOutput<Self::Answer2>;
+}
+
+/// A solution whose parts work on the input directly.
+pub struct Parts<F1, F2> {
+    year: Year,
+    day: Day,
+    part_one: F1,
+    part_two: F2,
+}
+
+impl<F1, F2> Parts<F1, F2> {
+    pub fn new<R1: PartResult, R2: PartResult>(
+        year: Year,
+        day: Day,
+        part_one: F1,
+        part_two: F2,
+    ) -> Self
+    where
+        F1: Fn(&str) -> R1,
+        F2: Fn(&str) -> R2,
+    {
+        Parts {
+            year,
+            day,
+            part_one,
+            part_two,
+        }
+    }
+}
+
+impl<F1, F2, R1: PartResult, R2: PartResult> Solution for Parts<F1, F2>
+where
+    F1: Fn(&str) -> R1,
+    F2: Fn(&str) -> R2,
+{
+    type Parsed<'a> = &'a str;
+    type Answer1 = R1::Answer;
+    type Answer2 = R2::Answer;
+
+    fn year(&self) -> Year {
+        self.year
+    }
+
+    fn day(&self) -> Day {
+        self.day
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> &'a str {
+        input
+    }
+
+    fn part_one(&self, parsed: &&str) -> PartOutput<R1::Answer> {
+        (self.part_one)(parsed).into_output()
+    }
+
+    fn part_two(&self, parsed: &&str) -> PartOutput<R2::Answer> {
+        (self.part_two)(parsed).into_output()
+    }
+}
+
+/// A solution whose parts share a parsed value of type `T`, which does not borrow from the input.
+pub struct ParsedParts<P, F1, F2> {
+    year: Year,
+    day: Day,
+    parse: P,
+    part_one: F1,
+    part_two: F2,
+}
+
+impl<P, F1, F2> ParsedParts<P, F1, F2> {
+    pub fn new<T, R1: PartResult, R2: PartResult>(
+        year: Year,
+        day: Day,
+        parse: P,
+        part_one: F1,
+        part_two: F2,
+    ) -> Self
+    where
+        P: Fn(&str) -> T,
+        F1: Fn(&T) -> R1,
+        F2: Fn(&T) -> R2,
+    {
+        ParsedParts {
+            year,
+            day,
+            parse,
+            part_one,
+            part_two,
+        }
+    }
+}
+
+impl<P, F1, F2, T, R1: PartResult, R2: PartResult> Solution for ParsedParts<P, F1, F2>
+where
+    P: Fn(&str) -> T,
+    F1: Fn(&T) -> R1,
+    F2: Fn(&T) -> R2,
+{
+    type Parsed<'a> = T;
+    type Answer1 = R1::Answer;
+    type Answer2 = R2::Answer;
+
+    fn year(&self) -> Year {
+        self.year
+    }
+
+    fn day(&self) -> Day {
+        self.day
+    }
+
+    fn parse(&self, input: &str) -> T {
+        (self.parse)(input)
+    }
+
+    fn part_one(&self, parsed: &T) -> PartOutput<R1::Answer> {
+        (self.part_one)(parsed).into_output()
+    }
+
+    fn part_two(&self, parsed: &T) -> PartOutput<R2::Answer> {
+        (self.part_two)(parsed).into_output()
+    }
+}
+
+/// Stands in for a part that a solution does not implement.
+pub fn unsolved<T: ?Sized>(_: &T) -> Option<Infallible> {
+    None
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{ParsedParts, PartOutput, Parts, Solution, unsolved};
+    use crate::{day, year};
+
+    /// Answers of both parts, the way generic tooling would get them.
+    fn answers<S: Solution>(solution: &S, input: &str) -> [PartOutput<String>; 2] {
+        let parsed = solution.parse(input);
+        [
+            solution.part_one(&parsed).map(|a| a.map(|a| a.to_string())),
+            solution.part_two(&parsed).map(|a| a.map(|a| a.to_string())),
+        ]
+    }
+
+    #[test]
+    fn runs_parts() {
+        let solution = Parts::new(
+            year!(2025),
+            day!(1),
+            |input: &str| Some(input.len()),
+            |input: &str| i64::try_from(input.len()).map(|n| -n),
+        );
+
+        assert_eq!(solution.day(), day!(1));
+        assert_eq!(
+            answers(&solution, "hello"),
+            [Ok(Some("5".into())), Ok(Some("-5".into()))]
+        );
+    }
+
+    #[test]
+    fn runs_parsed_parts() {
+        fn parse(input: &str) -> Vec<u32> {
+            input.split(',').map(|n| n.parse().unwrap()).collect()
+        }
+
+        fn part_one(numbers: &[u32]) -> Option<u32> {
+            Some(numbers.iter().sum())
+        }
+
+        let solution = ParsedParts::new(year!(2025), day!(3), parse, |p| part_one(p), unsolved);
+
+        assert_eq!(
+            answers(&solution, "1,2,3"),
+            [Ok(Some("6".into())), Ok(None)]
+        );
+    }
+
+    #[test]
+    fn reports_errors() {
+        let solution = Parts::new(
+            year!(2025),
+            day!(2),
+            |input: &str| input.trim().parse::<u32>(),
+            |input: &str| input.lines().next().map(str::len).ok_or("empty input"),
+        );
+
+        assert_eq!(
+            answers(&solution, "x"),
+            [
+                Err("invalid digit found in string".into()),
+                Ok(Some("1".into()))
+            ]
+        );
+        assert_eq!(answers(&solution, "")[1], Err("empty input".into()));
+    }
+}
@@ -0,0 +1,456 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    io::{Error, ErrorKind},
+    path::Path,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, Year};
+
+static SUBMISSIONS_FILE_PATH: &str = "./data/submissions.json";
+
+/// The server's judgement of a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, but the server did not say in which direction.
+    Incorrect,
+    /// Submitted too early after a previous wrong answer, the answer was not checked.
+    Wait,
+}
+
+impl Verdict {
+    /// Reads the verdict from the message the server responds with after a submission.
+    pub fn from_message(message: &str) -> Option<Self> {
+        if message.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if message.contains("too high") {
+            Some(Verdict::TooHigh)
+        } else if message.contains("too low") {
+            Some(Verdict::TooLow)
+        } else if message.contains("That's not the right answer") {
+            Some(Verdict::Incorrect)
+        } else if message.contains("You gave an answer too recently") {
+            Some(Verdict::Wait)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the server checked and rejected the answer.
+    pub fn is_rejected(self) -> bool {
+        matches!(
+            self,
+            Verdict::TooHigh | Verdict::TooLow | Verdict::Incorrect
+        )
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Incorrect => "incorrect",
+            Verdict::Wait => "wait",
+        })
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too high" => Ok(Verdict::TooHigh),
+            "too low" => Ok(Verdict::TooLow),
+            "incorrect" => Ok(Verdict::Incorrect),
+            "wait" => Ok(Verdict::Wait),
+            _ => Err(format!("Unknown verdict `{s}`.")),
+        }
+    }
+}
+
+/// A single answer sent to the server.
+#[derive(Clone, Debug)]
+pub struct Submission {
+    pub year: Year,
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    /// Seconds since the UNIX epoch.
+    pub timestamp: u64,
+    pub verdict: Verdict,
+}
+
+impl Submission {
+    /// Creates a submission timestamped with the current time.
+    pub fn now(year: Year, day: Day, part: u8, answer: &str, verdict: Verdict) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        Submission {
+            year,
+            day,
+            part,
+            answer: answer.to_string(),
+            timestamp,
+            verdict,
+        }
+    }
+}
+
+/// Reason for refusing to submit an answer.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadyCorrect(String),
+    AlreadyRejected(Verdict),
+    NotBelow(String),
+    NotAbove(String),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadyCorrect(answer) => {
+                write!(f, "this part was already solved with `{answer}`.")
+            }
+            Refusal::AlreadyRejected(verdict) => {
+                write!(f, "this answer was already rejected as {verdict}.")
+            }
+            Refusal::NotBelow(answer) => write!(f, "`{answer}` was already too high."),
+            Refusal::NotAbove(answer) => write!(f, "`{answer}` was already too low."),
+        }
+    }
+}
+
+/// Ledger of all answers submitted so far.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Submissions {
+    pub data: Vec<Submission>,
+}
+
+impl Submissions {
+    /// Dehydrate submissions to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(SUBMISSIONS_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate submissions from a JSON file. If not present, returns an empty ledger.
+    /// A file that cannot be read is an error, so that the next submission does not replace it.
+    pub fn read_from_file() -> Result<Self, String> {
+        Self::read_from_path(Path::new(SUBMISSIONS_FILE_PATH))
+    }
+
+    fn read_from_path(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(content) => Submissions::try_from(content)
+                .map_err(|e| format!("could not parse `{}`: {e}", path.display())),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Submissions::default()),
+            Err(e) => Err(format!("could not read `{}`: {e}", path.display())),
+        }
+    }
+
+    /// All submissions for one part of a day.
+    pub fn for_part(&self, year: Year, day: Day, part: u8) -> impl Iterator<Item = &Submission> {
+        self.data
+            .iter()
+            .filter(move |s| s.year == year && s.day == day && s.part == part)
+    }
+
+    /// The accepted answer for one part of a day, if any.
+    pub fn correct_answer(&self, year: Year, day: Day, part: u8) -> Option<&str> {
+        self.for_part(year, day, part)
+            .find(|s| s.verdict == Verdict::Correct)
+            .map(|s| s.answer.as_str())
+    }
+
+    /// Checks whether submitting `answer` could possibly be accepted given previous verdicts.
+    ///
+    /// Bounds from "too high" / "too low" verdicts only apply to integer answers.
+    pub fn check(&self, year: Year, day: Day, part: u8, answer: &str) -> Result<(), Refusal> {
+        if let Some(correct) = self.correct_answer(year, day, part) {
+            return Err(Refusal::AlreadyCorrect(correct.to_string()));
+        }
+
+        if let Some(previous) = self
+            .for_part(year, day, part)
+            .find(|s| s.verdict.is_rejected() && s.answer == answer)
+        {
+            return Err(Refusal::AlreadyRejected(previous.verdict));
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+
+        let bound = |verdict: Verdict| {
+            self.for_part(year, day, part)
+                .filter(move |s| s.verdict == verdict)
+                .filter_map(|s| Some((s.answer.parse::<i128>().ok()?, &s.answer)))
+        };
+
+        if let Some((_, high)) = bound(Verdict::TooHigh).find(|(h, _)| value >= *h) {
+            return Err(Refusal::NotBelow(high.clone()));
+        }
+
+        if let Some((_, low)) = bound(Verdict::TooLow).find(|(l, _)| value <= *l) {
+            return Err(Refusal::NotAbove(low.clone()));
+        }
+
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Submissions> for JsonValue {
+    fn from(value: Submissions) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Submissions {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Submissions {
+            data: json_data
+                .iter()
+                .map(Submission::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("year".into(), JsonValue::String(value.year.to_string()));
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert(
+            "verdict".into(),
+            JsonValue::String(value.verdict.to_string()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let year = json
+            .get("year")
+            .and_then(|v| v.get::<String>())
+            .and_then(|year| Year::from_str(year).ok())
+            .ok_or("Expected submission.year to be a Year struct.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected submission.day to be a Day struct.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|&part| part as u8)
+            .filter(|part| matches!(part, 1 | 2))
+            .ok_or("Expected submission.part to be 1 or 2.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|&t| t as u64)
+            .ok_or("Expected submission.timestamp to be a number.")?;
+
+        let verdict = json
+            .get("verdict")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.verdict to be a string.")?
+            .parse()?;
+
+        Ok(Submission {
+            year,
+            day,
+            part,
+            answer: answer.clone(),
+            timestamp,
+            verdict,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Refusal, Submission, Submissions, Verdict};
+    use crate::{day, year};
+    use std::{env, fs, process};
+
+    fn get_mock_submissions() -> Submissions {
+        let submission = |part, answer: &str, verdict| Submission {
+            year: year!(2025),
+            day: day!(1),
+            part,
+            answer: answer.into(),
+            timestamp: 1_764_565_200,
+            verdict,
+        };
+
+        Submissions {
+            data: vec![
+                submission(1, "100", Verdict::TooHigh),
+                submission(1, "10", Verdict::TooLow),
+                submission(1, "50", Verdict::Incorrect),
+                submission(1, "51", Verdict::Wait),
+                submission(2, "7", Verdict::Correct),
+            ],
+        }
+    }
+
+    #[test]
+    fn parses_verdicts() {
+        let message = |s| Verdict::from_message(s);
+        assert_eq!(
+            message("That's the right answer! You are one gold star closer."),
+            Some(Verdict::Correct)
+        );
+        assert_eq!(
+            message("That's not the right answer; your answer is too high."),
+            Some(Verdict::TooHigh)
+        );
+        assert_eq!(
+            message("That's not the right answer; your answer is too low."),
+            Some(Verdict::TooLow)
+        );
+        assert_eq!(
+            message("That's not the right answer. If you're stuck, ..."),
+            Some(Verdict::Incorrect)
+        );
+        assert_eq!(
+            message("You gave an answer too recently; you have 42s left to wait."),
+            Some(Verdict::Wait)
+        );
+        assert_eq!(
+            message("You don't seem to be solving the right level."),
+            None
+        );
+    }
+
+    #[test]
+    fn refuses_known_answers() {
+        let submissions = get_mock_submissions();
+        let check = |part, answer| submissions.check(year!(2025), day!(1), part, answer);
+
+        assert_eq!(
+            check(1, "50"),
+            Err(Refusal::AlreadyRejected(Verdict::Incorrect))
+        );
+        assert_eq!(
+            check(1, "100"),
+            Err(Refusal::AlreadyRejected(Verdict::TooHigh))
+        );
+        assert_eq!(check(2, "8"), Err(Refusal::AlreadyCorrect("7".into())));
+    }
+
+    #[test]
+    fn refuses_answers_out_of_bounds() {
+        let submissions = get_mock_submissions();
+        let check = |answer| submissions.check(year!(2025), day!(1), 1, answer);
+
+        assert_eq!(check("101"), Err(Refusal::NotBelow("100".into())));
+        assert_eq!(check("3"), Err(Refusal::NotAbove("10".into())));
+        assert_eq!(check("51"), Ok(()));
+        assert_eq!(check("11"), Ok(()));
+        assert_eq!(check("not a number"), Ok(()));
+    }
+
+    #[test]
+    fn ignores_other_days() {
+        let submissions = get_mock_submissions();
+        assert_eq!(submissions.check(year!(2025), day!(2), 1, "100"), Ok(()));
+        assert_eq!(submissions.check(year!(2024), day!(1), 2, "8"), Ok(()));
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let submissions = get_mock_submissions();
+        let json = tinyjson::JsonValue::from(submissions.clone())
+            .stringify()
+            .unwrap();
+        let parsed = Submissions::try_from(json).unwrap();
+        assert_eq!(parsed.data.len(), submissions.data.len());
+        assert_eq!(parsed.data[0].answer, "100");
+        assert_eq!(parsed.data[0].verdict, Verdict::TooHigh);
+        assert_eq!(parsed.data[4].part, 2);
+        assert_eq!(parsed.data[4].timestamp, 1_764_565_200);
+    }
+
+    #[test]
+    fn rejects_malformed_ledger() {
+        let path = env::temp_dir().join(format!("aoc-submissions-{}.json", process::id()));
+
+        let _ = fs::remove_file(&path);
+        assert!(Submissions::read_from_path(&path).unwrap().data.is_empty());
+
+        fs::write(&path, "{ \"data\": [").unwrap();
+        let result = Submissions::read_from_path(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert!(result.unwrap_err().starts_with("could not parse"));
+    }
+}
@@ -1,17 +1,268 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{collections::HashMap, fmt::Display, fs, io::Error, str::FromStr, time::Duration};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::{Day, Year};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+use super::memory::MemoryUsage;
+
+fn get_timings_path(year: Year) -> String {
+    format!("./data/{year}/timings.json")
+}
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
+    /// Time of the parser shared by both parts, if the solution has one.
+    pub parse: Option<String>,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
     pub total_nanos: f64,
+    /// Sample distribution of both parts. Missing for timings stored by older versions.
+    pub stats: [Option<Stats>; 2],
+    /// How the parts were benched. Missing for timings stored by older versions.
+    pub policy: Option<BenchPolicy>,
+    /// Heap usage of both parts, only recorded with `cargo time --memory`.
+    pub memory: [Option<MemoryUsage>; 2],
+    /// Error messages of parts that returned an error instead of an answer.
+    pub errors: [Option<String>; 2],
+}
+
+impl Timing {
+    /// The formatted time of a part, using the statistic if it was recorded.
+    pub fn part_time(&self, part: u8, statistic: Statistic) -> Option<String> {
+        let (time, stats) = match part {
+            1 => (&self.part_1, self.stats[0]),
+            2 => (&self.part_2, self.stats[1]),
+            _ => return None,
+        };
+
+        match (statistic, stats) {
+            (Statistic::Median, Some(stats)) => Some(format_nanos(stats.median)),
+            _ => time.clone(),
+        }
+    }
+
+    /// The mean time of a part in nanoseconds.
+    pub fn part_nanos(&self, part: u8) -> Option<f64> {
+        let (time, stats) = match part {
+            1 => (&self.part_1, self.stats[0]),
+            2 => (&self.part_2, self.stats[1]),
+            _ => return None,
+        };
+
+        // the stats hold the mean before it was rounded for display.
+        stats
+            .map(|s| s.mean)
+            .or_else(|| parse_nanos(time.as_deref()?))
+    }
+
+    /// The time of a part in nanoseconds, using the statistic if it was recorded.
+    pub fn part_nanos_by(&self, part: u8, statistic: Statistic) -> Option<f64> {
+        let stats = match part {
+            1 => self.stats[0],
+            2 => self.stats[1],
+            _ => return None,
+        };
+
+        match (statistic, stats) {
+            (Statistic::Median, Some(stats)) => Some(stats.median),
+            _ => self.part_nanos(part),
+        }
+    }
+
+    /// Total time of both parts in nanoseconds, using the statistic if it was recorded.
+    pub fn total(&self, statistic: Statistic) -> f64 {
+        let parse = || self.parse.as_deref().and_then(parse_nanos).unwrap_or(0_f64);
+
+        match (statistic, self.stats) {
+            (Statistic::Median, [Some(a), Some(b)]) => parse() + a.median + b.median,
+            (Statistic::Median, [Some(a), None]) => parse() + a.median,
+            (Statistic::Median, [None, Some(b)]) => parse() + b.median,
+            _ => self.total_nanos,
+        }
+    }
+}
+
+/// Which statistic of the benchmark samples represents a part in the readme.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Statistic {
+    #[default]
+    Mean,
+    Median,
+}
+
+impl FromStr for Statistic {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "mean" => Ok(Self::Mean),
+            "median" => Ok(Self::Median),
+            _ => Err(format!(
+                "unknown statistic `{s}`, expecting `mean` or `median`"
+            )),
+        }
+    }
+}
+
+/// How often a part is run when it is benched.
+///
+/// The runner aims for `bench_time` of total execution time, based on the duration of the first run,
+/// within `min_samples..=max_samples`. `warmup` runs are discarded before sampling.
+///
+/// # Display
+/// Displays as `budget 1.0s, 10-10000 samples, 0 warmup`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchPolicy {
+    pub bench_time: Duration,
+    pub min_samples: u128,
+    pub max_samples: u128,
+    pub warmup: u128,
+}
+
+impl Default for BenchPolicy {
+    fn default() -> Self {
+        Self {
+            bench_time: Duration::from_secs(1),
+            min_samples: 10,
+            max_samples: 10000,
+            warmup: 0,
+        }
+    }
+}
+
+impl BenchPolicy {
+    /// Checks that at least one sample is taken and the bounds are in order, as [`Self::samples`]
+    /// relies on it.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.min_samples == 0 || self.min_samples > self.max_samples {
+            return Err("expecting `1 <= --min-samples <= --max-samples`".into());
+        }
+        Ok(())
+    }
+
+    /// Number of samples to take for a part whose first run took `base_time`.
+    pub fn samples(&self, base_time: Duration) -> u128 {
+        (self.bench_time.as_nanos() / base_time.as_nanos().max(10))
+            .clamp(self.min_samples, self.max_samples)
+    }
+
+    /// Command-line arguments that pass the policy on to a solution bin.
+    pub fn to_args(&self) -> Vec<String> {
+        vec![
+            "--bench-time".into(),
+            format!("{}ns", self.bench_time.as_nanos()),
+            "--min-samples".into(),
+            self.min_samples.to_string(),
+            "--max-samples".into(),
+            self.max_samples.to_string(),
+            "--warmup".into(),
+            self.warmup.to_string(),
+        ]
+    }
+}
+
+impl Display for BenchPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "budget {:.1?}, {}-{} samples, {} warmup",
+            self.bench_time, self.min_samples, self.max_samples, self.warmup
+        )
+    }
+}
+
+/// Parse a duration like `500ms` or `2s`, a number without unit is read as seconds.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn parse_duration(s: &str) -> Result<Duration, String> {
+    parse_nanos(s)
+        .filter(|nanos| nanos.is_finite() && *nanos >= 0_f64)
+        .map(|nanos| Duration::from_nanos(nanos as u64))
+        .ok_or_else(|| format!("expecting a duration like `500ms` or `2s`, got `{s}`"))
+}
+
+/// Prefix of the line below a benched part that holds its [`Stats`].
+pub const STATS_PREFIX: &str = "  ↳ ";
+
+/// Distribution of the benchmark samples of one part, in nanoseconds.
+///
+/// # Display
+/// Displays as `min 1.0µs, median 1.2µs, p95 2.0µs, max 5.1µs, σ 0.3µs`, the mean is omitted
+/// since it is already printed with the answer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub mean: f64,
+    pub min: f64,
+    pub median: f64,
+    pub p95: f64,
+    pub max: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    /// Compute the statistics of a non-empty set of samples.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        nanos.sort_unstable_by(f64::total_cmp);
+
+        let n = nanos.len();
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n as f64;
+
+        let median = if n.is_multiple_of(2) {
+            f64::midpoint(nanos[n / 2 - 1], nanos[n / 2])
+        } else {
+            nanos[n / 2]
+        };
+
+        // nearest-rank percentile.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let p95 = nanos[((n as f64 * 0.95).ceil() as usize).clamp(1, n) - 1];
+
+        Stats {
+            mean,
+            min: nanos[0],
+            median,
+            p95,
+            max: nanos[n - 1],
+            stddev: variance.sqrt(),
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {}, median {}, p95 {}, max {}, σ {}",
+            format_nanos(self.min),
+            format_nanos(self.median),
+            format_nanos(self.p95),
+            format_nanos(self.max),
+            format_nanos(self.stddev),
+        )
+    }
+}
+
+/// Format nanoseconds the way durations are printed by the runner, e.g. `1.2ms`.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn format_nanos(nanos: f64) -> String {
+    format!("{:.1?}", Duration::from_nanos(nanos as u64))
+}
+
+/// Parse a duration printed by the runner, e.g. `1.2ms`, into nanoseconds.
+pub fn parse_nanos(s: &str) -> Option<f64> {
+    // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
+    let parse = |postfix: &str| s.split(postfix).next()?.parse::<f64>().ok();
+
+    match s {
+        s if s.contains("ns") => parse("ns"),
+        s if s.contains("µs") => parse("µs").map(|x| x * 1000_f64),
+        s if s.contains("ms") => parse("ms").map(|x| x * 1_000_000_f64),
+        _ => parse("s").map(|x| x * 1_000_000_000_f64),
+    }
 }
 
 /// Represents benchmark times for a set of days.
@@ -23,15 +274,15 @@ pub struct Timings {
 
 impl Timings {
     /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let mut file = fs::File::create(get_timings_path(year))?;
         json.format_to(&mut file)
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
+    pub fn read_from_file(year: Year) -> Self {
+        fs::read_to_string(get_timings_path(year))
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from)
             .unwrap_or_default()
@@ -57,7 +308,16 @@ impl Timings {
 
     /// Sum up total duration of timings as millis.
     pub fn total_millis(&self) -> f64 {
-        self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
+        self.total_millis_by(Statistic::Mean)
+    }
+
+    /// Sum up total duration of timings as millis, using the statistic where it was recorded.
+    pub fn total_millis_by(&self, statistic: Statistic) -> f64 {
+        self.data
+            .iter()
+            .map(|x| x.total(statistic))
+            .fold(0_f64, |acc, n| acc + n)
+            / 1_000_000_f64
     }
 
     pub fn is_day_complete(&self, day: Day) -> bool {
@@ -113,6 +373,13 @@ impl From<&Timing> for JsonValue {
 
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
+        map.insert(
+            "parse".into(),
+            value
+                .parse
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
 
         let part_1 = value.part_1.clone().map(JsonValue::String);
         let part_2 = value.part_2.clone().map(JsonValue::String);
@@ -133,6 +400,35 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        for (key, stats) in [
+            ("part_1_stats", value.stats[0]),
+            ("part_2_stats", value.stats[1]),
+        ] {
+            map.insert(key.into(), stats.map_or(JsonValue::Null, JsonValue::from));
+        }
+
+        map.insert(
+            "policy".into(),
+            value.policy.map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        for (key, memory) in [
+            ("part_1_memory", value.memory[0]),
+            ("part_2_memory", value.memory[1]),
+        ] {
+            map.insert(key.into(), memory.map_or(JsonValue::Null, JsonValue::from));
+        }
+
+        for (key, error) in [
+            ("part_1_error", &value.errors[0]),
+            ("part_2_error", &value.errors[1]),
+        ] {
+            map.insert(
+                key.into(),
+                error.clone().map_or(JsonValue::Null, JsonValue::String),
+            );
+        }
+
         JsonValue::Object(map)
     }
 }
@@ -166,11 +462,135 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        // stats were added later, older timings files do not have them.
+        let [stats_1, stats_2] = ["part_1_stats", "part_2_stats"].map(|key| {
+            json.get(key)
+                .filter(|v| !v.is_null())
+                .map(Stats::try_from)
+                .transpose()
+        });
+
+        let policy = json
+            .get("policy")
+            .filter(|v| !v.is_null())
+            .map(BenchPolicy::try_from)
+            .transpose()?;
+
+        let [memory_1, memory_2] = ["part_1_memory", "part_2_memory"].map(|key| {
+            json.get(key)
+                .filter(|v| !v.is_null())
+                .map(MemoryUsage::try_from)
+                .transpose()
+        });
+
+        let errors = ["part_1_error", "part_2_error"]
+            .map(|key| json.get(key).and_then(|v| v.get::<String>()).cloned());
+
+        // parse times were added later, older timings files do not have them.
+        let parse = json.get("parse").and_then(|v| v.get::<String>());
+
         Ok(Timing {
             day,
+            parse: parse.cloned(),
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
             total_nanos,
+            stats: [stats_1?, stats_2?],
+            policy,
+            memory: [memory_1?, memory_2?],
+            errors,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Stats> for JsonValue {
+    fn from(value: Stats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("mean".into(), JsonValue::Number(value.mean));
+        map.insert("min".into(), JsonValue::Number(value.min));
+        map.insert("median".into(), JsonValue::Number(value.median));
+        map.insert("p95".into(), JsonValue::Number(value.p95));
+        map.insert("max".into(), JsonValue::Number(value.max));
+        map.insert("stddev".into(), JsonValue::Number(value.stddev));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Stats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected stats.{key} to be a number."))
+        };
+
+        Ok(Stats {
+            mean: number("mean")?,
+            min: number("min")?,
+            median: number("median")?,
+            p95: number("p95")?,
+            max: number("max")?,
+            stddev: number("stddev")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[allow(clippy::cast_precision_loss)]
+impl From<BenchPolicy> for JsonValue {
+    fn from(value: BenchPolicy) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "bench_nanos".into(),
+            JsonValue::Number(value.bench_time.as_nanos() as f64),
+        );
+        map.insert(
+            "min_samples".into(),
+            JsonValue::Number(value.min_samples as f64),
+        );
+        map.insert(
+            "max_samples".into(),
+            JsonValue::Number(value.max_samples as f64),
+        );
+        map.insert("warmup".into(), JsonValue::Number(value.warmup as f64));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for BenchPolicy {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected policy to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>())
+                .map(|&x| x as u128)
+                .ok_or(format!("Expected policy.{key} to be a number."))
+        };
+
+        Ok(BenchPolicy {
+            bench_time: Duration::from_nanos(number("bench_nanos")? as u64),
+            min_samples: number("min_samples")?,
+            max_samples: number("max_samples")?,
+            warmup: number("warmup")?,
         })
     }
 }
@@ -188,21 +608,36 @@ mod tests {
             data: vec![
                 Timing {
                     day: day!(1),
+                    parse: None,
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    stats: [None, None],
+                    memory: [None, None],
+                    errors: [None, None],
+                    policy: None,
                 },
                 Timing {
                     day: day!(2),
+                    parse: None,
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
+                    stats: [None, None],
+                    memory: [None, None],
+                    errors: [None, None],
+                    policy: None,
                 },
                 Timing {
                     day: day!(4),
+                    parse: None,
                     part_1: Some("40ms".into()),
                     part_2: None,
                     total_nanos: 4e+10,
+                    stats: [None, None],
+                    memory: [None, None],
+                    errors: [None, None],
+                    policy: None,
                 },
             ],
         }
@@ -223,6 +658,17 @@ mod tests {
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_parse_timings() {
+            let json = r#"{ "data": [{ "day": "01", "parse": "3ms", "part_1": "1ms", "part_2": "2ms", "total_nanos": 6000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].parse, Some("3ms".to_string()));
+
+            let json = tinyjson::JsonValue::from(timings).stringify().unwrap();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].parse, Some("3ms".to_string()));
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -245,6 +691,125 @@ mod tests {
         }
     }
 
+    mod stats {
+        use std::time::Duration;
+
+        use crate::template::timings::{Stats, Timings};
+
+        #[test]
+        fn computes_stats() {
+            let samples: Vec<Duration> = (1..=20).map(Duration::from_nanos).collect();
+            let stats = Stats::from_samples(&samples);
+            assert_eq!(stats.mean, 10.5);
+            assert_eq!(stats.min, 1.0);
+            assert_eq!(stats.median, 10.5);
+            assert_eq!(stats.p95, 19.0);
+            assert_eq!(stats.max, 20.0);
+            assert!((stats.stddev - 5.766_281).abs() < 1e-6);
+        }
+
+        #[test]
+        fn roundtrips_stats() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "part_1_stats": { "mean": 1000000, "min": 1, "median": 2, "p95": 3, "max": 4, "stddev": 5 } }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let stats = timings.data[0].stats[0].unwrap();
+            assert_eq!(stats.median, 2.0);
+            assert!(timings.data[0].stats[1].is_none());
+
+            let json = tinyjson::JsonValue::from(timings).stringify().unwrap();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].stats[0], Some(stats));
+        }
+    }
+
+    mod bench_policy {
+        use std::time::Duration;
+
+        use crate::template::timings::{BenchPolicy, Timings, parse_duration};
+
+        #[test]
+        fn computes_samples() {
+            let policy = BenchPolicy {
+                bench_time: Duration::from_millis(500),
+                min_samples: 3,
+                max_samples: 100,
+                warmup: 0,
+            };
+            assert_eq!(policy.samples(Duration::from_millis(200)), 3);
+            assert_eq!(policy.samples(Duration::from_millis(20)), 25);
+            assert_eq!(policy.samples(Duration::from_nanos(1)), 100);
+        }
+
+        #[test]
+        fn validates_sample_bounds() {
+            let policy = BenchPolicy::default();
+            assert!(policy.validate().is_ok());
+            assert!(
+                BenchPolicy {
+                    max_samples: 0,
+                    ..policy
+                }
+                .validate()
+                .is_err()
+            );
+            assert!(
+                BenchPolicy {
+                    min_samples: 10,
+                    max_samples: 5,
+                    ..policy
+                }
+                .validate()
+                .is_err()
+            );
+        }
+
+        #[test]
+        fn parses_durations() {
+            assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+            assert_eq!(parse_duration("2s"), Ok(Duration::from_secs(2)));
+            assert_eq!(parse_duration("3"), Ok(Duration::from_secs(3)));
+            assert!(parse_duration("soon").is_err());
+        }
+
+        #[test]
+        fn roundtrips_policy() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "policy": { "bench_nanos": 500000000, "min_samples": 3, "max_samples": 100, "warmup": 2 } }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let policy = timings.data[0].policy.unwrap();
+            assert_eq!(policy.bench_time, Duration::from_millis(500));
+            assert_eq!(policy.warmup, 2);
+
+            let json = tinyjson::JsonValue::from(timings).stringify().unwrap();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].policy, Some(policy));
+        }
+
+        #[test]
+        fn roundtrips_memory() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": "2ms", "total_nanos": 3000000, "part_1_memory": { "peak_bytes": 2048, "allocations": 3 } }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let memory = timings.data[0].memory;
+            assert_eq!(memory[0].unwrap().peak_bytes, 2048);
+            assert!(memory[1].is_none());
+
+            let json = tinyjson::JsonValue::from(timings).stringify().unwrap();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].memory, memory);
+        }
+
+        #[test]
+        fn roundtrips_errors() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": null, "part_2": "2ms", "total_nanos": 2000000, "part_1_error": "no start tile" }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let errors = timings.data[0].errors.clone();
+            assert_eq!(errors, [Some("no start tile".into()), None]);
+
+            let json = tinyjson::JsonValue::from(timings).stringify().unwrap();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].errors, errors);
+        }
+    }
+
     mod serialization {
         use super::get_mock_timings;
         use std::collections::HashMap;
@@ -279,9 +844,14 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
+                    parse: None,
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
                     total_nanos: 3_000_000_000_f64,
+                    stats: [None, None],
+                    memory: [None, None],
+                    errors: [None, None],
+                    policy: None,
                 }],
             };
 
@@ -293,9 +863,14 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
+                    parse: None,
                     part_1: Some("1ms".into()),
                     part_2: None,
                     total_nanos: 1_000_000_000_f64,
+                    stats: [None, None],
+                    memory: [None, None],
+                    errors: [None, None],
+                    policy: None,
                 }],
             };
 
@@ -307,9 +882,14 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
+                    parse: None,
                     part_1: None,
                     part_2: None,
                     total_nanos: 0.0,
+                    stats: [None, None],
+                    memory: [None, None],
+                    errors: [None, None],
+                    policy: None,
                 }],
             };
 
@@ -331,9 +911,14 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(3),
+                    parse: None,
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    stats: [None, None],
+                    memory: [None, None],
+                    errors: [None, None],
+                    policy: None,
                 }],
             };
             let merged = timings.merge(&other);
@@ -351,9 +936,14 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(2),
+                    parse: None,
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    stats: [None, None],
+                    memory: [None, None],
+                    errors: [None, None],
+                    policy: None,
                 }],
             };
             let merged = timings.merge(&other);
@@ -0,0 +1,127 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::template::{AllDays, Day};
+
+/// The first year Advent of Code took place.
+pub const FIRST_YEAR: u16 = 2015;
+
+/// A valid year of Advent of Code (i.e. 2015 or later).
+///
+/// Years before 2025 have 25 days, later years have 12.
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// let year = Year::new(2024).unwrap();
+/// assert_eq!(year.to_string(), "2024");
+/// assert_eq!(year.day_count(), 25);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's in the valid range,
+    /// returns [`None`] otherwise.
+    pub const fn new(year: u16) -> Option<Self> {
+        if year < FIRST_YEAR {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    /// Reads the default year from the `AOC_YEAR` environment variable.
+    ///
+    /// `.cargo/config.toml` sets this variable for every `cargo` invocation,
+    /// so it acts as the default when no `--year` flag is passed.
+    pub fn from_env() -> Option<Self> {
+        std::env::var("AOC_YEAR").ok()?.parse().ok()
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// Number of puzzle days in this year's event.
+    pub const fn day_count(self) -> u8 {
+        if self.0 < 2025 { 25 } else { 12 }
+    }
+
+    /// Returns `true` if the [`Day`] is part of this year's event.
+    pub fn has_day(self, day: Day) -> bool {
+        day <= self.day_count()
+    }
+
+    /// An iterator that yields every day of this year's event.
+    pub fn days(self) -> AllDays {
+        AllDays::new(self)
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&format!("expecting a year of {FIRST_YEAR} or later"))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {
+        const {
+            $crate::template::Year::new($year)
+                .expect("invalid year, expecting a value of 2015 or later")
+        }
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Year;
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2015".parse::<Year>().unwrap(), Year(2015));
+        assert_eq!("2025".parse::<Year>().unwrap(), Year(2025));
+        assert!("2014".parse::<Year>().is_err());
+        assert!("twenty".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn counts_days() {
+        assert_eq!(Year(2015).day_count(), 25);
+        assert_eq!(Year(2024).day_count(), 25);
+        assert_eq!(Year(2025).day_count(), 12);
+    }
+}